@@ -37,23 +37,27 @@ enum Event {
 
 #[macroquad::main("UI Example 001")]
 async fn main() {
+    let mut layer = UILayer::new(1.0, root());
     loop {
         if is_key_pressed(Escape) {
             break;
         }
-        do_frame();
+        do_frame(&mut layer);
         next_frame().await;
     }
 }
 
-fn do_frame() {
+fn do_frame(layer: &mut UILayer<Event>) {
     clear_background(BLACK);
-    let mut layer = UILayer::new(1.0, root());
     layer.update();
-    for event in layer.get_events() {
+    let events: Vec<Event> = layer.get_events().cloned().collect();
+    for event in events {
         match event {
             Event::Click(item) => {
                 println!("clicked {}", item);
+                layer.patch_key(&item_key(item), |node| {
+                    *node = item_label(item, true).key(item_key(item));
+                });
             }
             Event::Hover(_) => {}
             Event::Pressed(_) => {}
@@ -62,16 +66,33 @@ fn do_frame() {
     layer.draw();
 }
 
-fn root() -> Node<Event> {
-    let text_1 = TextStyle {
+fn text_style() -> TextStyle {
+    TextStyle {
         font_size: 32.0,
         color: WHITE,
         shadow: Some(vec![
             (vec2(0.0, -2.0), BLACK),
             (vec2(-2.0, 0.0), BLACK),
             (vec2(-2.0, -2.0), BLACK),
-        ])
-    };
+        ]),
+        ..Default::default()
+    }
+}
+
+fn item_key(item: usize) -> String {
+    format!("item {}", item)
+}
+
+fn item_label(item: usize, clicked: bool) -> Node<Event> {
+    if clicked {
+        label(format!("Item {:?} (clicked)", item), text_style())
+    } else {
+        label(format!("Item {:?}", item), text_style())
+    }
+}
+
+fn root() -> Node<Event> {
+    let text_1 = text_style();
 
     horizontal_node([
         left_panel(&text_1),
@@ -132,7 +153,7 @@ fn left_panel(text_1: &TextStyle) -> Node<Event> {
                             ]
                         )))
                         .set(single_content(
-                            label(format!("Item {:?}", i), text_1)
+                            item_label(i, false).key(item_key(i))
                         ))
                     )
                     .to_vec())
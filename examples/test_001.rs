@@ -1,10 +1,10 @@
-use macroquad::color::{BLUE, GRAY, WHITE};
+use macroquad::color::{GRAY, WHITE};
 use macroquad::input::is_key_pressed;
 use macroquad::input::KeyCode::Escape;
 use macroquad::window::next_frame;
 use rust_macroquad_ui::basic_composites::label::label;
 use rust_macroquad_ui::basic_composites::stretch::{stretch_vertical};
-use rust_macroquad_ui::primitives::{ vertical_content, width};
+use rust_macroquad_ui::primitives::vertical_content;
 use rust_macroquad_ui::primitives::border::border;
 use rust_macroquad_ui::primitives::node::node;
 use rust_macroquad_ui::{UILayer};
@@ -1,9 +1,9 @@
-use macroquad::color::{BLUE, GRAY, WHITE};
+use macroquad::color::WHITE;
 use macroquad::input::is_key_pressed;
 use macroquad::input::KeyCode::Escape;
 use macroquad::window::next_frame;
 use rust_macroquad_ui::basic_composites::label::label;
-use rust_macroquad_ui::primitives::{layers, width};
+use rust_macroquad_ui::primitives::layers;
 use rust_macroquad_ui::primitives::node::node;
 use rust_macroquad_ui::{UILayer};
 
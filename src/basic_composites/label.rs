@@ -1,8 +1,7 @@
 use std::fmt::Debug;
-use macroquad::text::{measure_text, TextDimensions};
-use crate::primitives::{height, text, width};
+use crate::primitives::{text, width_shrink};
 use crate::primitives::node::{node, Node};
-use crate::primitives::text::TextStyle;
+use crate::primitives::text::{TextOverflow, TextStyle};
 
 impl <T: Into<TextStyle> + Clone> From<&T> for TextStyle {
     fn from(v: &T) -> Self {
@@ -12,16 +11,13 @@ impl <T: Into<TextStyle> + Clone> From<&T> for TextStyle {
 }
 
 pub fn label<Event: Clone + Debug + 'static, T: Into<String>, S: Into<TextStyle>>(t: T, style: S) -> Node<Event> {
-    let t = t.into();
     let style = style.into();
-    let size = measure_self(t.as_str(), style.font_size);
-
-    node().name("label")
-        .set(text(t, style.clone()))
-        .set(width(size.width))
-        .set(height(style.font_size))
-}
-
-fn measure_self(text: &str, font_size: f32) -> TextDimensions {
-    measure_text(text, None, font_size as u16, 1.0)
+    let overflow = style.overflow;
+    let label = node().name("label")
+        .set(text(t, style));
+    // labels that handle overflow give up width only when there isn't enough of it
+    match overflow {
+        TextOverflow::Visible => label,
+        _ => label.set(width_shrink()),
+    }
 }
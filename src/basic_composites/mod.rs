@@ -1,7 +1,11 @@
 pub mod margin;
 pub mod label;
+pub mod paragraph;
+pub mod rich_text;
 pub mod stretch;
 pub mod no_stretch;
 pub mod background;
 pub mod icon;
 pub mod node_factories;
+pub mod scroll_view;
+pub mod virtual_list;
@@ -8,6 +8,7 @@ use crate::basic_composites::stretch;
 use crate::basic_composites::stretch::StretchSide;
 use crate::Node;
 use crate::primitives::{height, horizontal_content, vertical_content, width};
+use crate::primitives::group::Align;
 use crate::primitives::node::{node, NodePadding};
 
 pub fn horizontal_node<Event: 'static + Clone + Debug, T: Into<Vec<Node<Event>>>>(items: T) -> Node<Event> {
@@ -16,6 +17,12 @@ pub fn horizontal_node<Event: 'static + Clone + Debug, T: Into<Vec<Node<Event>>>
         .set(horizontal_content(items))
 }
 
+pub fn baseline_node<Event: 'static + Clone + Debug, T: Into<Vec<Node<Event>>>>(items: T) -> Node<Event> {
+    node()
+        .name("baseline node")
+        .set(horizontal_content(items).align(Align::Baseline))
+}
+
 pub fn vertical_node<Event: 'static + Clone + Debug, T: Into<Vec<Node<Event>>>>(items: T) -> Node<Event> {
     node()
         .name("vertical node")
@@ -0,0 +1,12 @@
+use std::fmt::Debug;
+
+use crate::primitives::width_stretch;
+use crate::primitives::node::{node, Node};
+use crate::primitives::paragraph::Paragraph;
+use crate::primitives::text::TextStyle;
+
+pub fn paragraph<Event: Clone + Debug + 'static, T: Into<String>, S: Into<TextStyle>>(t: T, style: S) -> Node<Event> {
+    node().name("paragraph")
+        .set(Paragraph { value: t.into(), style: style.into() })
+        .set(width_stretch())
+}
@@ -0,0 +1,11 @@
+use std::fmt::Debug;
+
+use crate::primitives::width_stretch;
+use crate::primitives::node::{node, Node};
+use crate::primitives::rich_text::{RichText, Span};
+
+pub fn rich_text<Event: Clone + Debug + 'static, T: Into<Vec<Span>>>(spans: T) -> Node<Event> {
+    node().name("rich_text")
+        .set(RichText { spans: spans.into() })
+        .set(width_stretch())
+}
@@ -0,0 +1,20 @@
+use std::fmt::Debug;
+
+use crate::primitives::{clip, scroll, scroll_content};
+use crate::primitives::group::Axis;
+use crate::primitives::node::{node, Node};
+use crate::primitives::scroll::Scrollbar;
+
+pub fn scroll_view<Event: Clone + Debug + 'static>(axis: Axis, content: Node<Event>) -> Node<Event> {
+    node().name("scroll_view")
+        .set(clip())
+        .set(scroll_content(axis, [content]))
+        .set(scroll(axis))
+}
+
+pub fn scroll_view_with_scrollbar<Event: Clone + Debug + 'static>(axis: Axis, scrollbar: Scrollbar, content: Node<Event>) -> Node<Event> {
+    node().name("scroll_view")
+        .set(clip())
+        .set(scroll_content(axis, [content]))
+        .set(scroll(axis).scrollbar(scrollbar))
+}
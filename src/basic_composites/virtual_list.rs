@@ -0,0 +1,14 @@
+use std::fmt::Debug;
+
+use crate::primitives::{height, width_stretch};
+use crate::primitives::node::{node, Node};
+use crate::primitives::virtual_list::VirtualList;
+
+pub fn virtual_list<Event, F>(item_count: usize, row_height: f32, builder: F) -> Node<Event>
+    where Event: Clone + Debug + 'static, F: Fn(usize) -> Node<Event> + 'static
+{
+    node().name("virtual_list")
+        .set(VirtualList::new(item_count, row_height, builder))
+        .set(width_stretch())
+        .set(height(item_count as f32 * row_height))
+}
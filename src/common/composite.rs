@@ -16,7 +16,7 @@ impl Composite {
         }
     }
 
-    pub fn get<T: 'static>(&self) -> Option<Map<Iter<TypeBox>, for<'r> fn(&'r TypeBox) -> &'r T>> {
+    pub fn get<T: 'static>(&self) -> Option<Map<Iter<'_, TypeBox>, for<'r> fn(&'r TypeBox) -> &'r T>> {
         let type_id = TypeId::of::<T>();
         let components = self.components.get(&type_id);
         if let Some(components) = components {
@@ -27,16 +27,16 @@ impl Composite {
         None
     }
 
-    pub fn get_mut<T: Default + Clone + Debug + 'static>(&mut self) -> Map<IterMut<TypeBox>, fn(&mut TypeBox) -> &mut T> {
+    pub fn get_mut<T: Default + Clone + Debug + 'static>(&mut self) -> Map<IterMut<'_, TypeBox>, fn(&mut TypeBox) -> &mut T> {
         let type_id = TypeId::of::<T>();
         let entry = self.components
             .entry(type_id)
-            .or_insert_with(Vec::new);
+            .or_default();
         entry.push(Self::create_type_box::<T>(Default::default()));
         Self::boxes_to_refs_mut::<T>(entry)
     }
 
-    fn boxes_to_refs_mut<T: 'static>(it: &mut Vec<TypeBox>) -> Map<IterMut<TypeBox>, fn(&mut TypeBox) -> &mut T> {
+    fn boxes_to_refs_mut<T: 'static>(it: &mut [TypeBox]) -> Map<IterMut<'_, TypeBox>, fn(&mut TypeBox) -> &mut T> {
         it.iter_mut().map(|it| it.raw.downcast_mut::<T>().unwrap())
     }
 
@@ -50,16 +50,29 @@ impl Composite {
         }
     }
 
-    // pub fn remove<T: Clone + Debug + Sized + 'static>(&mut self) -> Option<T> {
-    //     let type_id = TypeId::of::<T>();
-    //     self.components.remove(&type_id).map(|it| *it.raw.downcast::<T>().unwrap())
-    // }
+    pub fn get_existing_mut<T: 'static>(&mut self) -> Option<Map<IterMut<'_, TypeBox>, fn(&mut TypeBox) -> &mut T>> {
+        let type_id = TypeId::of::<T>();
+        self.components.get_mut(&type_id).map(|it| Self::boxes_to_refs_mut::<T>(it))
+    }
+
+    pub fn get_box(&self, type_id: TypeId, index: usize) -> &TypeBox {
+        &self.components[&type_id][index]
+    }
+
+    pub fn len_of(&self, type_id: TypeId) -> usize {
+        self.components.get(&type_id).map_or(0, |it| it.len())
+    }
+
+    pub fn remove<T: 'static>(&mut self) {
+        let type_id = TypeId::of::<T>();
+        self.components.remove(&type_id);
+    }
 
     pub fn insert<T: Clone + Debug + 'static>(&mut self, value: T) {
         let type_id = TypeId::of::<T>();
         let entry = self.components
             .entry(type_id)
-            .or_insert_with(Vec::new);
+            .or_default();
         entry.push(Self::create_type_box::<T>(value));
     }
 }
@@ -70,6 +83,12 @@ pub struct TypeBox {
     debugger: fn(&TypeBox, f: &mut Formatter<'_>) -> std::fmt::Result,
 }
 
+impl TypeBox {
+    pub fn cast_ref<T: 'static>(&self) -> Option<&T> {
+        self.raw.downcast_ref::<T>()
+    }
+}
+
 impl Clone for TypeBox {
     fn clone(&self) -> Self {
         let cloner = self.cloner;
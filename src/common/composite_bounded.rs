@@ -1,62 +1,60 @@
-
 #[macro_export] macro_rules! make_bounded_composite {
     ($vis: vis, $struct_name: ident $(<$struct_name_param:ident>)?, $bound: ident $(<$bound_param:ident>)?) => {
 
         #[derive(core::clone::Clone, std::fmt::Debug)]
         $vis struct $struct_name$(<$struct_name_param>)? {
-            target: crate::common::composite::Composite,
-            ptrs: Vec<*const dyn $bound$(<$bound_param>)?>,
+            target: $crate::common::composite::Composite,
+            order: Vec<(
+                std::any::TypeId,
+                usize,
+                for<'r> fn(&'r $crate::common::composite::TypeBox) -> &'r dyn $bound$(<$bound_param>)?,
+            )>,
         }
 
         impl $(<$struct_name_param>)? $struct_name$(<$struct_name_param>)? {
             pub fn new() -> $struct_name$(<$struct_name_param>)? {
                 $struct_name {
-                    target: crate::common::composite::Composite::new(),
-                    ptrs: vec![],
+                    target: $crate::common::composite::Composite::new(),
+                    order: vec![],
                 }
             }
 
             pub fn get<T: $bound$(<$bound_param>)? + 'static>(&self) -> Option<
                 std::iter::Map<
-                    std::slice::Iter<crate::common::composite::TypeBox>,
-                    for<'r> fn(&'r crate::common::composite::TypeBox) -> &'r T
+                    std::slice::Iter<'_, $crate::common::composite::TypeBox>,
+                    for<'r> fn(&'r $crate::common::composite::TypeBox) -> &'r T
                 >
             > {
                 self.target.get::<T>()
             }
 
-            // pub fn remove<T: $bound$(<$bound_param>)? + Clone + std::fmt::Debug + Sized + 'static>(&mut self) -> Option<T> {
-            //     if let Some(prev_index) = self.ptr_indices.get(&std::any::TypeId::of::<T>()).cloned() {
-            //         self.ptr_indices.remove(&std::any::TypeId::of::<T>());
-            //         self.ptrs.remove(prev_index);
-            //         self.target.remove::<T>()
-            //     } else {
-            //         None
-            //     }
-            // }
+            pub fn get_mut<T: $bound$(<$bound_param>)? + 'static>(&mut self) -> Option<
+                std::iter::Map<
+                    std::slice::IterMut<'_, $crate::common::composite::TypeBox>,
+                    fn(&mut $crate::common::composite::TypeBox) -> &mut T
+                >
+            > {
+                self.target.get_existing_mut::<T>()
+            }
 
-            // pub fn put<T: $bound$(<$bound_param>)? + Clone + std::fmt::Debug + 'static>(&mut self, value: T) {
-            //     self.remove::<T>();
-            //     self.target.put::<T>(value);
-            //     self.ptrs.push(self.target.get::<T>().unwrap());
-            //     self.ptr_indices.insert(std::any::TypeId::of::<T>(), self.ptrs.len() - 1);
-            // }
+            pub fn remove<T: $bound$(<$bound_param>)? + 'static>(&mut self) {
+                let type_id = std::any::TypeId::of::<T>();
+                self.order.retain(|(it, _, _)| *it != type_id);
+                self.target.remove::<T>();
+            }
 
             pub fn insert<T: $bound$(<$bound_param>)? + Clone + std::fmt::Debug + 'static>(&mut self, value: T) {
+                let type_id = std::any::TypeId::of::<T>();
                 self.target.insert::<T>(value);
-                self.ptrs.push(self.target.get::<T>().unwrap().last().unwrap());
+                self.order.push((
+                    type_id,
+                    self.target.len_of(type_id) - 1,
+                    |it| it.cast_ref::<T>().unwrap(),
+                ));
             }
 
-            pub fn iter<'a>(&'a self) -> std::iter::Map<
-                std::slice::Iter<
-                    'a,
-                    *const dyn $bound$(<$bound_param>)?
-                >,
-                fn(
-                    &'a *const dyn $bound$(<$bound_param>)?
-                ) -> &'a dyn $bound$(<$bound_param>)?
-            > {
-                self.ptrs.iter().map(|value| unsafe { value.as_ref().unwrap() })
+            pub fn iter(&self) -> impl Iterator<Item=&dyn $bound$(<$bound_param>)?> {
+                self.order.iter().map(|(type_id, index, caster)| caster(self.target.get_box(*type_id, *index)))
             }
         }
     };
@@ -64,4 +62,4 @@
 
 // trait TestTrait {}
 
-// make_bounded_composite!(,TestTraitComposite, TestTrait);
\ No newline at end of file
+// make_bounded_composite!(,TestTraitComposite, TestTrait);
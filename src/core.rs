@@ -1,21 +1,35 @@
 use std::cell::RefCell;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter};
 
-use macroquad::math::Rect;
+use macroquad::math::{Rect, Vec2};
+use crate::input::InputState;
+use crate::layout::LayoutNode;
+use crate::primitives::group::Axis;
+use crate::render::{MacroquadRenderer, Renderer};
+use crate::state::StateStore;
 
 
 #[derive(Clone, Copy, Debug)]
 pub enum Phase<'a, Event> {
-    Draw { events: &'a Vec<Event> },
-    CollectEvents { collected: &'a RefCell<Vec<Event>> },
+    Layout,
+    Draw { events: &'a Vec<Event>, renderer: &'a dyn Renderer },
+    CollectEvents { collected: &'a RefCell<Vec<Event>>, input: &'a InputState },
 }
 
 #[derive(Clone, Copy, Debug)]
 pub struct Ctx<'a, Event> {
     pub area: Rect,
+    pub viewport: Rect,
+    pub clip: Option<Rect>,
     pub scale: f32,
     pub phase: Phase<'a, Event>,
     pub path: UiPathStep<'a>,
+    pub layout: Option<&'a LayoutNode>,
+    pub states: Option<&'a RefCell<StateStore>>,
+    // measures text in every phase, while drawing goes through the renderer of `Phase::Draw`
+    pub renderer: &'a dyn Renderer,
+    // set while a parent derives its size from its children, before its own area is known
+    pub measuring: bool,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -24,46 +38,100 @@ pub enum UiPathStep<'a> {
     Index(usize, Option<&'a UiPathStep<'a>>),
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UiPathSegment {
+    Name(&'static str),
+    Index(usize),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UiPath {
+    pub segments: Vec<UiPathSegment>,
+}
+
 pub trait Element<Event> {
     fn do_phase(&self, _ctx: Ctx<Event>) {}
+    fn layout(&self, _ctx: Ctx<Event>) -> Vec<LayoutNode> {
+        vec![]
+    }
+    fn baseline(&self, _ctx: Ctx<Event>) -> Option<f32> {
+        None
+    }
+    fn measure(&self, _axis: Axis, _cross_area_size: Option<f32>, _ctx: Ctx<Event>) -> Option<f32> {
+        None
+    }
     fn expand_padding(self) -> Self
         where Self: Sized {
         self
     }
 }
 
+impl<'a> UiPathStep<'a> {
+    pub fn to_path(&self) -> UiPath {
+        let mut segments = vec![];
+        let mut _step = Some(self);
+        while let Some(step) = _step {
+            _step = match *step {
+                UiPathStep::Name(name, parent) => {
+                    segments.push(UiPathSegment::Name(name));
+                    parent
+                }
+                UiPathStep::Index(index, parent) => {
+                    segments.push(UiPathSegment::Index(index));
+                    parent
+                }
+            };
+        }
+        segments.reverse();
+        UiPath { segments }
+    }
+}
+
+impl Display for UiPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for segment in self.segments.iter() {
+            match segment {
+                UiPathSegment::Name(name) => write!(f, "/{}", name)?,
+                UiPathSegment::Index(index) => write!(f, "/{}", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<'a, Event: Clone> Ctx<'a, Event> {
     pub fn new(area: Rect, scale: f32, phase: Phase<'a, Event>) -> Self {
         Ctx {
             area,
+            viewport: area,
+            clip: None,
             scale,
             phase,
             path: UiPathStep::Name("root", None),
+            layout: None,
+            states: None,
+            renderer: &MacroquadRenderer,
+            measuring: false,
         }
     }
 
     pub fn backtrace(&self) -> String {
-        let mut parts = vec![];
-        let mut _step = Some(&self.path);
-        while let Some(step) = _step {
-            _step = match *step {
-                UiPathStep::Name(name, parent) => {
-                    parts.push(name.to_owned());
-                    parent
-                }
-                UiPathStep::Index(index, parent) => {
-                    parts.push(format!("{}", index));
-                    parent
-                }
-            };
-        }
+        self.path.to_path().to_string()
+    }
 
-        let mut s = String::new();
-        for part in parts.iter().rev() {
-            s.push('/');
-            s.push_str(part);
+    pub fn state<T: Debug + Clone + 'static>(&self) -> Option<T> {
+        let states = self.states?.borrow();
+        states.get::<T>(&self.backtrace()).cloned()
+    }
+
+    pub fn put_state<T: Debug + Clone + 'static>(&self, value: T) {
+        if let Some(states) = self.states {
+            states.borrow_mut().put(&self.backtrace(), value);
         }
-        s
+    }
+
+    pub fn hits(&self, point: Vec2) -> bool {
+        self.area.contains(point) && self.clip.is_none_or(|clip| clip.contains(point))
     }
 
     pub fn clone_with<F: Fn(&mut Self)>(&self, f: F) -> Self {
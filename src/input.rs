@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+
+use macroquad::input::{is_mouse_button_down, is_mouse_button_pressed, is_mouse_button_released, mouse_position, mouse_wheel, MouseButton};
+use macroquad::math::Vec2;
+
+const BUTTONS: [MouseButton; 3] = [MouseButton::Left, MouseButton::Right, MouseButton::Middle];
+
+#[derive(Debug, Clone, Default)]
+pub struct InputState {
+    pub mouse_position: Vec2,
+    pub mouse_wheel: Vec2,
+    pub buttons_down: HashSet<MouseButton>,
+    pub buttons_pressed: HashSet<MouseButton>,
+    pub buttons_released: HashSet<MouseButton>,
+}
+
+impl InputState {
+    pub fn new() -> InputState {
+        Default::default()
+    }
+
+    pub fn capture() -> InputState {
+        InputState {
+            mouse_position: Vec2::from(mouse_position()),
+            mouse_wheel: Vec2::from(mouse_wheel()),
+            buttons_down: BUTTONS.into_iter().filter(|it| is_mouse_button_down(*it)).collect(),
+            buttons_pressed: BUTTONS.into_iter().filter(|it| is_mouse_button_pressed(*it)).collect(),
+            buttons_released: BUTTONS.into_iter().filter(|it| is_mouse_button_released(*it)).collect(),
+        }
+    }
+
+    pub fn mouse_at(mut self, position: Vec2) -> Self {
+        self.mouse_position = position;
+        self
+    }
+
+    pub fn wheel(mut self, delta: Vec2) -> Self {
+        self.mouse_wheel = delta;
+        self
+    }
+
+    pub fn press(mut self, button: MouseButton) -> Self {
+        self.buttons_pressed.insert(button);
+        self.buttons_down.insert(button);
+        self
+    }
+
+    pub fn hold(mut self, button: MouseButton) -> Self {
+        self.buttons_down.insert(button);
+        self
+    }
+
+    pub fn release(mut self, button: MouseButton) -> Self {
+        self.buttons_released.insert(button);
+        self.buttons_down.remove(&button);
+        self
+    }
+
+    pub fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+        self.buttons_down.contains(&button)
+    }
+
+    pub fn is_mouse_button_pressed(&self, button: MouseButton) -> bool {
+        self.buttons_pressed.contains(&button)
+    }
+
+    pub fn is_mouse_button_released(&self, button: MouseButton) -> bool {
+        self.buttons_released.contains(&button)
+    }
+}
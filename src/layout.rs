@@ -0,0 +1,41 @@
+use macroquad::math::Rect;
+
+use crate::core::UiPath;
+
+#[derive(Clone, Debug)]
+pub struct LayoutNode {
+    pub name: Option<&'static str>,
+    pub path: UiPath,
+    pub rect: Rect,
+    pub children: Vec<LayoutNode>,
+}
+
+#[derive(Clone, Debug)]
+pub struct LayoutTree {
+    pub root: LayoutNode,
+}
+
+impl LayoutNode {
+    pub fn iter(&self) -> impl Iterator<Item=&LayoutNode> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let next = stack.pop()?;
+            stack.extend(next.children.iter().rev());
+            Some(next)
+        })
+    }
+}
+
+impl LayoutTree {
+    pub fn iter(&self) -> impl Iterator<Item=&LayoutNode> {
+        self.root.iter()
+    }
+
+    pub fn find(&self, name: &str) -> Option<&LayoutNode> {
+        self.iter().find(|it| it.name == Some(name))
+    }
+
+    pub fn get(&self, path: &str) -> Option<&LayoutNode> {
+        self.iter().find(|it| it.path.to_string() == path)
+    }
+}
@@ -1,27 +1,107 @@
 #![allow(clippy::new_without_default)]
+#![allow(clippy::type_complexity)]
 
 use macroquad::math::Rect;
 use macroquad::prelude::{screen_height, screen_width};
-use std::cell::RefCell;
+use std::cell::{Cell, Ref, RefCell};
+use std::fmt::Debug;
 use std::mem;
+use std::rc::Rc;
 use std::slice::Iter;
 use crate::core::{Ctx, Phase};
-use crate::primitives::node::Node;
+use crate::input::InputState;
+use crate::layout::LayoutTree;
+use crate::primitives::node::{node, Node};
+use crate::render::{MacroquadRenderer, Renderer};
+use crate::state::{reconcile, StateStore};
 
 pub mod primitives;
 pub mod core;
 pub mod basic_composites;
 pub mod common;
+pub mod input;
+pub mod layout;
+pub mod render;
+pub mod state;
 
 pub struct UILayer<Event> {
     events: Vec<Event>,
     scale: f32,
-    root: Node<Event>
+    area: Option<Rect>,
+    root: Node<Event>,
+    layout: RefCell<Option<LayoutTree>>,
+    layout_version: Cell<u64>,
+    states: RefCell<StateStore>,
+    renderer: Rc<dyn Renderer>,
 }
 
-impl<Event: Clone> UILayer<Event> {
+impl<Event: Clone + Debug + 'static> UILayer<Event> {
     pub fn new(scale: f32, root: Node<Event>) -> UILayer<Event> {
-        UILayer { events: Default::default(), scale, root }
+        UILayer { events: Default::default(), scale, area: None, root: root.expand_padding(), layout: Default::default(), layout_version: Default::default(), states: Default::default(), renderer: Rc::new(MacroquadRenderer) }
+    }
+
+    /// Lays the UI out in the given rect instead of the whole macroquad screen.
+    pub fn with_area(mut self, area: Rect) -> Self {
+        self.area = Some(area);
+        self
+    }
+
+    /// Draws with and measures text through the given renderer instead of macroquad.
+    pub fn with_renderer(mut self, renderer: Rc<dyn Renderer>) -> Self {
+        self.renderer = renderer;
+        *self.layout.get_mut() = None;
+        self
+    }
+
+    pub fn root(&self) -> &Node<Event> {
+        &self.root
+    }
+
+    pub fn set_root(&mut self, root: Node<Event>) {
+        let root = root.expand_padding();
+        self.states.get_mut().migrate(reconcile(&self.root, &root));
+        self.root = root;
+        *self.layout.get_mut() = None;
+    }
+
+    pub fn states(&self) -> Ref<'_, StateStore> {
+        self.states.borrow()
+    }
+
+    /// Any write lays the whole tree out again on the next frame; measured sizes don't
+    /// depend on state, so they are taken from each node's cache.
+    pub fn states_mut(&mut self) -> &mut StateStore {
+        self.states.get_mut()
+    }
+
+    /// Edits the node at `path` in place. Only that node and its ancestors measure again;
+    /// the positions of the whole tree are recomputed on the next frame.
+    pub fn patch<F: FnOnce(&mut Node<Event>)>(&mut self, path: &str, f: F) -> bool {
+        match self.root.route_to_path(path) {
+            Some(route) => {
+                self.patch_route(&route, f);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn patch_key<F: FnOnce(&mut Node<Event>)>(&mut self, key: &str, f: F) -> bool {
+        match self.root.route_to_key(key) {
+            Some(route) => {
+                self.patch_route(&route, f);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn patch_route<F: FnOnce(&mut Node<Event>)>(&mut self, route: &[usize], f: F) {
+        let target = self.root.descend_mut(route);
+        f(target);
+        *target = mem::replace(target, node()).expand_padding();
+        target.size_cache = Default::default();
+        *self.layout.get_mut() = None;
     }
 
     pub fn get_events(&self) -> Iter<'_, Event> {
@@ -29,20 +109,63 @@ impl<Event: Clone> UILayer<Event> {
     }
 
     pub fn update(&mut self) {
+        self.update_with(&InputState::capture());
+    }
+
+    pub fn update_with(&mut self, input: &InputState) {
         self.events.clear();
         let events = RefCell::new(mem::take(&mut self.events));
-        self.root.do_phase(Ctx::new(
-            screen_rect(),
-            self.scale,
-            Phase::CollectEvents {
-                collected: &events
-            },
-        ));
+        {
+            let layout = self.cached_layout();
+            self.root.do_phase(self.ctx(Phase::CollectEvents {
+                collected: &events,
+                input,
+            }).clone_with(|ctx| ctx.layout = Some(&layout.root)));
+        }
         self.events = events.take();
     }
 
     pub fn draw(&self) {
-        self.root.do_phase(Ctx::new(screen_rect(), self.scale, Phase::Draw { events: &self.events }));
+        self.draw_with(&*self.renderer);
+    }
+
+    pub fn draw_with(&self, renderer: &dyn Renderer) {
+        let layout = self.cached_layout();
+        self.root.do_phase(self.ctx(Phase::Draw { events: &self.events, renderer })
+            .clone_with(|ctx| ctx.layout = Some(&layout.root)));
+    }
+
+    pub fn layout(&self) -> LayoutTree {
+        self.cached_layout().clone()
+    }
+
+    // positions are recomputed as a whole when anything changed, which is linear in the
+    // tree size since the quadratic part, measuring, hits the per-node size caches
+    fn cached_layout(&self) -> Ref<'_, LayoutTree> {
+        let area = self.area();
+        let version = self.states.borrow().version();
+        let outdated = match &*self.layout.borrow() {
+            Some(layout) => layout.root.rect != area || self.layout_version.get() != version,
+            None => true,
+        };
+        if outdated {
+            let root = self.root.layout(self.ctx(Phase::Layout));
+            *self.layout.borrow_mut() = Some(LayoutTree { root });
+            self.layout_version.set(self.states.borrow().version());
+        }
+        Ref::map(self.layout.borrow(), |it| it.as_ref().unwrap())
+    }
+
+    fn ctx<'a>(&'a self, phase: Phase<'a, Event>) -> Ctx<'a, Event> {
+        Ctx::new(self.area(), self.scale, phase)
+            .clone_with(|ctx| {
+                ctx.states = Some(&self.states);
+                ctx.renderer = &*self.renderer;
+            })
+    }
+
+    fn area(&self) -> Rect {
+        self.area.unwrap_or_else(screen_rect)
     }
 }
 
@@ -0,0 +1,90 @@
+use std::fmt::Debug;
+
+use macroquad::math::{Rect, Vec2};
+
+use crate::core::{Ctx, Element};
+use crate::primitives::group::{calc_size_dimension, clamp_size, CalculatedSize, DimensionKey};
+use crate::primitives::node::Node;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Placement {
+    Anchored { anchor: Anchor, offset: Vec2 },
+    Absolute(Rect),
+}
+
+impl<Event> Element<Event> for Placement {}
+
+#[derive(Debug, Copy, Clone)]
+enum Edge {
+    Start,
+    Center,
+    End,
+}
+
+impl Anchor {
+    fn edges(self) -> (Edge, Edge) {
+        match self {
+            Anchor::TopLeft => (Edge::Start, Edge::Start),
+            Anchor::Top => (Edge::Center, Edge::Start),
+            Anchor::TopRight => (Edge::End, Edge::Start),
+            Anchor::Left => (Edge::Start, Edge::Center),
+            Anchor::Center => (Edge::Center, Edge::Center),
+            Anchor::Right => (Edge::End, Edge::Center),
+            Anchor::BottomLeft => (Edge::Start, Edge::End),
+            Anchor::Bottom => (Edge::Center, Edge::End),
+            Anchor::BottomRight => (Edge::End, Edge::End),
+        }
+    }
+}
+
+impl Edge {
+    // offsets point inwards from the anchored edge
+    fn position(self, start: f32, extent: f32, size: f32, offset: f32) -> f32 {
+        match self {
+            Edge::Start => start + offset,
+            Edge::Center => start + (extent - size) / 2.0 + offset,
+            Edge::End => start + extent - size - offset,
+        }
+    }
+}
+
+pub(crate) fn layout<Event: Clone + Debug + 'static>(
+    children: &[Node<Event>],
+    ctx: &Ctx<Event>,
+) -> Vec<Rect> {
+    children.iter().enumerate()
+        .map(|(i, child)| match child.unique::<Placement>() {
+            None => ctx.area,
+            Some(Placement::Absolute(rect)) => rect.offset(ctx.area.point()),
+            Some(Placement::Anchored { anchor, offset }) => {
+                let ctx = ctx.step_down_i(i);
+                let resolve = |size: CalculatedSize, extent: f32| match size {
+                    CalculatedSize::Fixed(value) => value,
+                    CalculatedSize::Stretch { min, max, .. } => clamp_size(extent, min, max),
+                };
+                let width = resolve(calc_size_dimension(child, DimensionKey::Horizontal, Some(ctx.area.h), &ctx), ctx.area.w);
+                let height = resolve(calc_size_dimension(child, DimensionKey::Vertical, Some(width), &ctx), ctx.area.h);
+                let (horizontal, vertical) = anchor.edges();
+                Rect::new(
+                    horizontal.position(ctx.area.x, ctx.area.w, width, offset.x),
+                    vertical.position(ctx.area.y, ctx.area.h, height, offset.y),
+                    width,
+                    height,
+                )
+            }
+        })
+        .collect()
+}
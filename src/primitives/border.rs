@@ -1,5 +1,4 @@
 use macroquad::color::Color;
-use macroquad::shapes::draw_rectangle_lines;
 use crate::core::{Ctx, Element, Phase};
 
 #[derive(Debug, Copy, Clone)]
@@ -11,10 +10,10 @@ pub struct Border {
 impl<Event> Element<Event> for Border {
     fn do_phase(&self, ctx: Ctx<Event>)  {
         match ctx.phase {
-            Phase::Draw { .. } => {
-                draw_rectangle_lines(ctx.area.x, ctx.area.y, ctx.area.w, ctx.area.h, self.thickness, self.color);
+            Phase::Draw { renderer, .. } => {
+                renderer.draw_rectangle_lines(ctx.area, self.thickness, self.color);
             }
-            Phase::CollectEvents { .. } => {}
+            Phase::Layout | Phase::CollectEvents { .. } => {}
         }
     }
 }
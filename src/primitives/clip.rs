@@ -0,0 +1,21 @@
+use macroquad::math::Rect;
+
+use crate::core::{Ctx, Element};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Clip;
+
+impl<Event> Element<Event> for Clip {}
+
+// nested clips only ever shrink; a clip outside its parent clip leaves an empty rect
+pub(crate) fn clip_rect<Event>(ctx: &Ctx<Event>) -> Rect {
+    intersect_clip(ctx.clip, ctx.area)
+}
+
+pub(crate) fn intersect_clip(clip: Option<Rect>, area: Rect) -> Rect {
+    match clip {
+        None => area,
+        Some(parent) => parent.intersect(area)
+            .unwrap_or(Rect::new(area.x, area.y, 0.0, 0.0)),
+    }
+}
@@ -1,5 +1,4 @@
 use macroquad::color::Color;
-use macroquad::shapes::draw_rectangle;
 use crate::core::{Ctx, Element, Phase};
 
 #[derive(Debug, Copy, Clone)]
@@ -16,10 +15,10 @@ impl From<Color> for ColorFill {
 impl<Event> Element<Event> for ColorFill {
     fn do_phase(&self, ctx: Ctx<Event>) {
         match ctx.phase {
-            Phase::Draw { .. } => {
-                draw_rectangle(ctx.area.x, ctx.area.y, ctx.area.w, ctx.area.h, self.color);
+            Phase::Draw { renderer, .. } => {
+                renderer.draw_rectangle(ctx.area, self.color);
             }
-            Phase::CollectEvents { .. } => {}
+            Phase::Layout | Phase::CollectEvents { .. } => {}
         }
     }
 }
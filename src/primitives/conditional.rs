@@ -22,7 +22,7 @@ impl<Event, Target: Element<Event>> EventBased<Event, Target> {
 impl<Event: Eq + PartialEq, Target: Element<Event>> Element<Event> for EventBased<Event, Target> {
     fn do_phase(&self, ctx: Ctx<Event>) {
         match ctx.phase {
-            Phase::Draw { events } => {
+            Phase::Draw { events, .. } => {
                 for (event, target) in &self.conditional {
                     if events.contains(event) {
                         if let Some(target) = target {
@@ -35,7 +35,7 @@ impl<Event: Eq + PartialEq, Target: Element<Event>> Element<Event> for EventBase
                     target.do_phase(ctx);
                 }
             }
-            Phase::CollectEvents { .. } => {}
+            Phase::Layout | Phase::CollectEvents { .. } => {}
         }
     }
 }
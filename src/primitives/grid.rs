@@ -0,0 +1,244 @@
+use std::fmt::Debug;
+
+use macroquad::math::Rect;
+
+use crate::core::{Ctx, Element};
+use crate::primitives::group::{calc_size_dimension, CalculatedSize, DimensionKey};
+use crate::primitives::node::Node;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Track {
+    Fixed(f32),
+    Stretch(f32),
+    Auto,
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    pub columns: Vec<Track>,
+    pub rows: Vec<Track>,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GridSpan {
+    pub columns: usize,
+    pub rows: usize,
+}
+
+impl<Event> Element<Event> for GridSpan {}
+
+#[derive(Debug, Copy, Clone)]
+struct GridCell {
+    column: usize,
+    row: usize,
+    columns: usize,
+    rows: usize,
+}
+
+impl GridCell {
+    fn start(&self, dimension: DimensionKey) -> usize {
+        match dimension {
+            DimensionKey::Horizontal => self.column,
+            DimensionKey::Vertical => self.row,
+        }
+    }
+
+    fn span(&self, dimension: DimensionKey) -> usize {
+        match dimension {
+            DimensionKey::Horizontal => self.columns,
+            DimensionKey::Vertical => self.rows,
+        }
+    }
+}
+
+impl Grid {
+    pub fn new<T: Into<Vec<Track>>>(columns: T) -> Grid {
+        Grid { columns: columns.into(), rows: vec![] }
+    }
+
+    pub fn with_rows<T: Into<Vec<Track>>>(mut self, rows: T) -> Grid {
+        self.rows = rows.into();
+        self
+    }
+
+    pub(crate) fn measure<Event: Clone + Debug + 'static>(
+        &self,
+        children: &[Node<Event>],
+        dimension: DimensionKey,
+        cross_area_size: Option<f32>,
+        ctx: &Ctx<Event>,
+    ) -> CalculatedSize {
+        let cells = self.place(children);
+        let tracks = self.tracks(dimension, &cells);
+        let cross_sizes = match (dimension, cross_area_size) {
+            (DimensionKey::Vertical, Some(width)) => self.column_widths(children, &cells, width, ctx),
+            _ => vec![None; children.len()],
+        };
+        let auto_sizes = auto_sizes(children, &cells, &tracks, dimension, &cross_sizes, ctx);
+        let mut fixed_part = 0.0;
+        let mut weight = None;
+        for (track, auto_size) in tracks.iter().zip(auto_sizes) {
+            match track {
+                Track::Fixed(value) => fixed_part += value,
+                Track::Stretch(track_weight) => weight = Some(weight.unwrap_or(0.0f32).max(*track_weight)),
+                Track::Auto => fixed_part += auto_size,
+            }
+        }
+        if let Some(weight) = weight {
+            CalculatedSize::stretch(fixed_part, weight)
+        } else {
+            CalculatedSize::Fixed(fixed_part)
+        }
+    }
+
+    pub(crate) fn layout<Event: Clone + Debug + 'static>(
+        &self,
+        children: &[Node<Event>],
+        ctx: &Ctx<Event>,
+    ) -> Vec<Rect> {
+        let cells = self.place(children);
+        let column_widths = self.column_widths(children, &cells, ctx.area.w, ctx);
+        let offsets = |dimension: DimensionKey| {
+            let tracks = self.tracks(dimension, &cells);
+            let cross_sizes = match dimension {
+                DimensionKey::Horizontal => vec![None; children.len()],
+                DimensionKey::Vertical => column_widths.clone(),
+            };
+            let auto_sizes = auto_sizes(children, &cells, &tracks, dimension, &cross_sizes, ctx);
+            let (start, available) = match dimension {
+                DimensionKey::Horizontal => (ctx.area.x, ctx.area.w),
+                DimensionKey::Vertical => (ctx.area.y, ctx.area.h),
+            };
+            let sizes = resolve_tracks(&tracks, &auto_sizes, available);
+            let mut offsets = vec![start];
+            for size in sizes {
+                offsets.push(offsets.last().unwrap() + size);
+            }
+            offsets
+        };
+        let columns = offsets(DimensionKey::Horizontal);
+        let rows = offsets(DimensionKey::Vertical);
+        cells.iter()
+            .map(|cell| Rect::new(
+                columns[cell.column],
+                rows[cell.row],
+                columns[cell.column + cell.columns] - columns[cell.column],
+                rows[cell.row + cell.rows] - rows[cell.row],
+            ))
+            .collect()
+    }
+
+    fn column_widths<Event: Clone + Debug + 'static>(
+        &self,
+        children: &[Node<Event>],
+        cells: &[GridCell],
+        width: f32,
+        ctx: &Ctx<Event>,
+    ) -> Vec<Option<f32>> {
+        let tracks = self.tracks(DimensionKey::Horizontal, cells);
+        let cross_sizes = vec![None; children.len()];
+        let auto_sizes = auto_sizes(children, cells, &tracks, DimensionKey::Horizontal, &cross_sizes, ctx);
+        let sizes = resolve_tracks(&tracks, &auto_sizes, width);
+        cells.iter()
+            .map(|cell| Some(sizes[cell.column..cell.column + cell.columns].iter().sum()))
+            .collect()
+    }
+
+    fn column_count(&self) -> usize {
+        self.columns.len().max(1)
+    }
+
+    fn tracks(&self, dimension: DimensionKey, cells: &[GridCell]) -> Vec<Track> {
+        match dimension {
+            DimensionKey::Horizontal => {
+                let mut columns = self.columns.clone();
+                columns.resize(self.column_count(), Track::Auto);
+                columns
+            }
+            DimensionKey::Vertical => {
+                let row_count = cells.iter()
+                    .map(|it| it.row + it.rows)
+                    .max()
+                    .unwrap_or(0)
+                    .max(self.rows.len());
+                let mut rows = self.rows.clone();
+                rows.resize(row_count, Track::Auto);
+                rows
+            }
+        }
+    }
+
+    fn place<Event: Clone + Debug + 'static>(&self, children: &[Node<Event>]) -> Vec<GridCell> {
+        let column_count = self.column_count();
+        let mut occupied: Vec<Vec<bool>> = vec![];
+        let mut cursor = (0, 0);
+        let mut cells = Vec::with_capacity(children.len());
+        for child in children {
+            let span = child.unique::<GridSpan>().copied().unwrap_or(GridSpan { columns: 1, rows: 1 });
+            let columns = span.columns.clamp(1, column_count);
+            let rows = span.rows.max(1);
+            let fits = |occupied: &Vec<Vec<bool>>, row: usize, column: usize| {
+                (row..row + rows).all(|r| (column..column + columns)
+                    .all(|c| !occupied.get(r).is_some_and(|it| it[c])))
+            };
+            let (mut row, mut column) = cursor;
+            while column + columns > column_count || !fits(&occupied, row, column) {
+                column += 1;
+                if column + columns > column_count {
+                    column = 0;
+                    row += 1;
+                }
+            }
+            while occupied.len() < row + rows {
+                occupied.push(vec![false; column_count]);
+            }
+            for occupied_row in &mut occupied[row..row + rows] {
+                occupied_row[column..column + columns].fill(true);
+            }
+            cursor = (row, column + columns);
+            cells.push(GridCell { column, row, columns, rows });
+        }
+        cells
+    }
+}
+
+fn auto_sizes<Event: Clone + Debug + 'static>(
+    children: &[Node<Event>],
+    cells: &[GridCell],
+    tracks: &[Track],
+    dimension: DimensionKey,
+    cross_sizes: &[Option<f32>],
+    ctx: &Ctx<Event>,
+) -> Vec<f32> {
+    let mut sizes = vec![0.0f32; tracks.len()];
+    for (i, ((child, cell), cross_area_size)) in children.iter().zip(cells).zip(cross_sizes).enumerate() {
+        let track = cell.start(dimension);
+        if cell.span(dimension) != 1 || !matches!(tracks[track], Track::Auto) {
+            continue;
+        }
+        let size = calc_size_dimension(child, dimension, *cross_area_size, &ctx.step_down_i(i));
+        sizes[track] = sizes[track].max(size.get_fixed_part());
+    }
+    sizes
+}
+
+fn resolve_tracks(tracks: &[Track], auto_sizes: &[f32], available: f32) -> Vec<f32> {
+    let mut fixed_total = 0.0;
+    let mut weight_total = 0.0;
+    for (track, auto_size) in tracks.iter().zip(auto_sizes) {
+        match track {
+            Track::Fixed(value) => fixed_total += value,
+            Track::Stretch(weight) => weight_total += weight,
+            Track::Auto => fixed_total += auto_size,
+        }
+    }
+    let free = (available - fixed_total).max(0.0);
+    tracks.iter().zip(auto_sizes)
+        .map(|(track, auto_size)| match track {
+            Track::Fixed(value) => *value,
+            Track::Stretch(weight) if weight_total > 0.0 => free * weight / weight_total,
+            Track::Stretch(_) => 0.0,
+            Track::Auto => *auto_size,
+        })
+        .collect()
+}
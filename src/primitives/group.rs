@@ -1,42 +1,164 @@
+use std::cell::RefCell;
 use std::fmt::Debug;
-use macroquad::math::Rect;
+use macroquad::math::{vec2, Rect, Vec2};
 use crate::core::Element;
 use crate::core::Ctx;
+use crate::layout::LayoutNode;
+use crate::primitives::anchor::{self, Placement};
+use crate::primitives::grid::Grid;
+use crate::primitives::{scroll, wrap};
 use crate::primitives::node::Node;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub enum Layout {
     Layered,
     Vertical,
     Horizontal,
+    Grid(Grid),
+    Wrap(Axis),
+    Scroll(Axis),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Axis {
+    Horizontal,
+    Vertical,
 }
 
 #[derive(Debug, Copy, Clone)]
-pub struct Width(pub Dimension);
+pub struct Width(pub Dimension, pub Bounds);
 
 #[derive(Debug, Copy, Clone)]
-pub struct Height(pub Dimension);
+pub struct Height(pub Dimension, pub Bounds);
+
+// min/max apply to whatever size the dimension resolves to
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Bounds {
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+}
+
+impl Bounds {
+    fn apply(self, size: CalculatedSize) -> CalculatedSize {
+        match size {
+            CalculatedSize::Fixed(value) => CalculatedSize::Fixed(clamp_size(value, self.min, self.max)),
+            CalculatedSize::Stretch { fixed_part, weight, min, max } => CalculatedSize::Stretch {
+                fixed_part,
+                weight,
+                min: self.min.or(min),
+                max: self.max.or(max),
+            },
+        }
+    }
+}
+
+impl Width {
+    pub fn new(dimension: Dimension) -> Self {
+        Width(dimension, Bounds::default())
+    }
+
+    pub fn min(self, value: f32) -> Self {
+        Width(self.0, Bounds { min: Some(value), ..self.1 })
+    }
+
+    pub fn max(self, value: f32) -> Self {
+        Width(self.0, Bounds { max: Some(value), ..self.1 })
+    }
+}
+
+impl Height {
+    pub fn new(dimension: Dimension) -> Self {
+        Height(dimension, Bounds::default())
+    }
+
+    pub fn min(self, value: f32) -> Self {
+        Height(self.0, Bounds { min: Some(value), ..self.1 })
+    }
+
+    pub fn max(self, value: f32) -> Self {
+        Height(self.0, Bounds { max: Some(value), ..self.1 })
+    }
+}
 
 impl<Event> Element<Event> for Width {}
 
 impl<Event> Element<Event> for Height {}
 
+#[derive(Debug, Copy, Clone)]
+pub struct AspectRatio(pub f32);
+
+impl<Event> Element<Event> for AspectRatio {}
+
 #[derive(Debug, Copy, Clone)]
 pub enum Dimension {
     Fixed(f32),
-    Stretch { fixed_part: f32 },
+    Percent(f32),
+    ViewportWidth(f32),
+    ViewportHeight(f32),
+    Stretch { fixed_part: f32, weight: f32 },
     RemoveStretch,
+    Shrink,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Align {
+    Start,
+    Center,
+    End,
+    Stretch,
+    Baseline,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct AlignSelf(pub Align);
+
+impl<Event> Element<Event> for AlignSelf {}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Justify {
+    Start,
+    Center,
+    End,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
 }
 
 #[derive(Debug, Clone)]
 pub struct Group<Event> {
     layout: Layout,
+    align: Align,
+    justify: Justify,
+    gap: f32,
     children: Vec<Node<Event>>,
 }
 
 impl<Event> Group<Event> {
     pub fn new(layout: Layout, children: Vec<Node<Event>>) -> Self {
-        Group { layout, children }
+        Group { layout, align: Align::Stretch, justify: Justify::Start, gap: 0.0, children }
+    }
+
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn children(&self) -> &[Node<Event>] {
+        &self.children
+    }
+
+    pub(crate) fn children_mut(&mut self) -> &mut Vec<Node<Event>> {
+        &mut self.children
     }
 }
 
@@ -46,6 +168,9 @@ impl<Event: Clone + Debug + 'static> Element<Event> for Group<Event> {
     {
         Group {
             layout: self.layout,
+            align: self.align,
+            justify: self.justify,
+            gap: self.gap,
             children: self.children.into_iter()
                 .map(|it| it.expand_padding())
                 .collect()
@@ -53,174 +178,578 @@ impl<Event: Clone + Debug + 'static> Element<Event> for Group<Event> {
     }
 
     fn do_phase(&self, ctx: Ctx<Event>) {
-        match self.layout {
-            Layout::Layered => {
-                for child in &self.children {
-                    child.do_phase(ctx.clone());
-                }
+        let computed;
+        let children_layout = match ctx.layout {
+            Some(layout) => &layout.children,
+            None => {
+                computed = self.layout(ctx.clone());
+                &computed
             }
-            Layout::Vertical => self.do_layout(&ctx, DimensionKey::Vertical),
-            Layout::Horizontal => self.do_layout(&ctx, DimensionKey::Horizontal),
+        };
+        for (i, (child, layout)) in self.children.iter().zip(children_layout).enumerate() {
+            step_down_child(&ctx, i, child, |ctx| child.do_phase(ctx.clone_with(|ctx| {
+                ctx.area = layout.rect;
+                ctx.layout = Some(layout);
+            })));
         }
     }
+
+    // the first child baseline as placed in this group's own area; a horizontal group
+    // with baseline-aligned children reports the line they share
+    fn baseline(&self, ctx: Ctx<Event>) -> Option<f32> {
+        let on_baseline = |child: &Node<Event>| self.effective_align(child, DimensionKey::Horizontal) == Align::Baseline;
+        let shared_line = matches!(self.layout, Layout::Horizontal) && self.children.iter().any(on_baseline);
+        self.children.iter().zip(self.areas(&ctx)).enumerate()
+            .filter(|(_, (child, _))| !child.is_hidden() && (!shared_line || on_baseline(child)))
+            .find_map(|(i, (child, area))| {
+                step_down_child(&ctx, i, child, |ctx| child.baseline(ctx.clone_with(|ctx| ctx.area = area)))
+                    .map(|it| it + area.y - ctx.area.y)
+            })
+    }
+
+    fn layout(&self, ctx: Ctx<Event>) -> Vec<LayoutNode> {
+        self.children.iter().zip(self.areas(&ctx)).enumerate()
+            .map(|(i, (child, area))| step_down_child(&ctx, i, child, |ctx| {
+                child.layout(ctx.clone_with(|ctx| ctx.area = area))
+            }))
+            .collect()
+    }
+}
+
+fn axis_rect(dimension: DimensionKey, origin: Vec2, size: f32, cross_size: f32) -> Rect {
+    match dimension {
+        DimensionKey::Horizontal => Rect::new(origin.x, origin.y, size, cross_size),
+        DimensionKey::Vertical => Rect::new(origin.x, origin.y, cross_size, size),
+    }
+}
+
+pub(crate) fn step_down_child<Event: Clone, R, F: FnOnce(Ctx<Event>) -> R>(
+    ctx: &Ctx<Event>,
+    i: usize,
+    child: &Node<Event>,
+    f: F,
+) -> R {
+    let ctx = ctx.step_down_i(i);
+    if let Some(name) = child.get_name() {
+        f(ctx.step_down(name))
+    } else {
+        f(ctx)
+    }
 }
 
 impl<Event: Clone + Debug + 'static> Group<Event> {
-    fn do_layout(&self, ctx: &Ctx<Event>, dimension: DimensionKey) {
+    fn visible_count(&self) -> usize {
+        self.children.iter().filter(|it| !it.is_hidden()).count()
+    }
+
+    fn total_gap(&self) -> f32 {
+        self.gap * self.visible_count().saturating_sub(1) as f32
+    }
+
+    fn areas(&self, ctx: &Ctx<Event>) -> Vec<Rect> {
+        match &self.layout {
+            Layout::Layered => anchor::layout(&self.children, ctx),
+            Layout::Vertical => self.do_layout(ctx, DimensionKey::Vertical),
+            Layout::Horizontal => self.do_layout(ctx, DimensionKey::Horizontal),
+            Layout::Grid(grid) => grid.layout(&self.children, ctx),
+            Layout::Wrap(axis) => wrap::layout(&self.children, (*axis).into(), self.gap, ctx),
+            Layout::Scroll(axis) => scroll::layout(&self.children, (*axis).into(), ctx),
+        }
+    }
+
+    fn effective_align(&self, child: &Node<Event>, dimension: DimensionKey) -> Align {
+        match child.unique::<AlignSelf>().map_or(self.align, |it| it.0) {
+            Align::Baseline if dimension == DimensionKey::Vertical => Align::Start,
+            align => align,
+        }
+    }
+
+    // children sharing a baseline need room for the tallest part above it and the deepest below
+    fn baseline_line_height(&self, ctx: &Ctx<Event>, widths: &[Option<f32>]) -> Option<f32> {
+        let mut ascent = None::<f32>;
+        let mut descent = 0.0f32;
+        for (i, (child, width)) in self.children.iter().zip(widths).enumerate() {
+            if child.is_hidden() || self.effective_align(child, DimensionKey::Horizontal) != Align::Baseline {
+                continue;
+            }
+            let child_ctx = ctx.step_down_i(i);
+            let width = width.unwrap_or_else(|| calc_size_dimension(child, DimensionKey::Horizontal, None, &child_ctx).get_fixed_part());
+            let height = calc_size_dimension(child, DimensionKey::Vertical, Some(width), &child_ctx).get_fixed_part();
+            let area = Rect::new(ctx.area.x, ctx.area.y, width, height);
+            let baseline = step_down_child(ctx, i, child, |ctx| child.baseline(ctx.clone_with(|ctx| ctx.area = area)))
+                .unwrap_or(height);
+            ascent = Some(ascent.unwrap_or(0.0).max(baseline));
+            descent = descent.max(height - baseline);
+        }
+        ascent.map(|it| it + descent)
+    }
+
+    fn do_layout(&self, ctx: &Ctx<Event>, dimension: DimensionKey) -> Vec<Rect> {
+        let sizes = self.distribute(
+            ctx,
+            dimension,
+            dimension.of(ctx.area.size()),
+            Some(dimension.cross().of(ctx.area.size())),
+        );
+        let visible_count = self.visible_count();
+        let free_size = dimension.of(ctx.area.size()) - self.total_gap() - sizes.iter().sum::<f32>();
+        let (leading, spacing) = match self.justify {
+            _ if free_size <= 0.0 || visible_count == 0 => (0.0, 0.0),
+            Justify::Start => (0.0, 0.0),
+            Justify::Center => (free_size / 2.0, 0.0),
+            Justify::End => (free_size, 0.0),
+            Justify::SpaceBetween if visible_count == 1 => (0.0, 0.0),
+            Justify::SpaceBetween => (0.0, free_size / (visible_count - 1) as f32),
+            Justify::SpaceAround => (free_size / visible_count as f32 / 2.0, free_size / visible_count as f32),
+            Justify::SpaceEvenly => (free_size / (visible_count + 1) as f32, free_size / (visible_count + 1) as f32),
+        };
+        let mut offset = leading + match dimension {
+            DimensionKey::Horizontal => ctx.area.x,
+            DimensionKey::Vertical => ctx.area.y,
+        };
+        let mut placed_any = false;
+        let (cross_start, cross_extent) = match dimension {
+            DimensionKey::Horizontal => (ctx.area.y, ctx.area.h),
+            DimensionKey::Vertical => (ctx.area.x, ctx.area.w),
+        };
+        let aligns: Vec<_> = self.children.iter()
+            .map(|child| self.effective_align(child, dimension))
+            .collect();
+        let cross_sizes: Vec<_> = sizes.iter().zip(&aligns).enumerate()
+            .map(|(i, (size, align))| match align {
+                Align::Stretch if !has_aspect_cross(&self.children[i], dimension.cross()) => cross_extent,
+                _ => match calc_size_dimension(&self.children[i], dimension.cross(), Some(*size), &ctx.step_down_i(i)) {
+                    CalculatedSize::Fixed(value) => value,
+                    CalculatedSize::Stretch { min, max, .. } => clamp_size(cross_extent, min, max),
+                },
+            })
+            .collect();
+        // children without a baseline of their own sit on the line with their bottom edge
+        let baselines: Vec<_> = self.children.iter().zip(&aligns).enumerate()
+            .map(|(i, (child, align))| match align {
+                Align::Baseline => {
+                    let area = axis_rect(dimension, ctx.area.point(), sizes[i], cross_sizes[i]);
+                    let baseline = step_down_child(ctx, i, child, |ctx| child.baseline(ctx.clone_with(|ctx| ctx.area = area)));
+                    Some(baseline.unwrap_or(cross_sizes[i]))
+                }
+                _ => None,
+            })
+            .collect();
+        let max_baseline = baselines.iter().flatten().copied().reduce(f32::max).unwrap_or(0.0);
+        let mut areas = Vec::with_capacity(sizes.len());
+        for (i, (size, align)) in sizes.into_iter().zip(aligns).enumerate() {
+            if !self.children[i].is_hidden() {
+                if placed_any {
+                    offset += self.gap + spacing;
+                }
+                placed_any = true;
+            }
+            let cross_size = cross_sizes[i];
+            let cross_offset = match align {
+                Align::Start | Align::Stretch => 0.0,
+                Align::Center => (cross_extent - cross_size) / 2.0,
+                Align::End => cross_extent - cross_size,
+                Align::Baseline => max_baseline - baselines[i].unwrap_or(0.0),
+            };
+            let origin = match dimension {
+                DimensionKey::Horizontal => vec2(offset, cross_start + cross_offset),
+                DimensionKey::Vertical => vec2(cross_start + cross_offset, offset),
+            };
+            areas.push(axis_rect(dimension, origin, size, cross_size));
+            offset += size;
+        }
+        areas
+    }
+
+    fn distribute(
+        &self,
+        ctx: &Ctx<Event>,
+        dimension: DimensionKey,
+        forward_area_size: f32,
+        cross_area_size: Option<f32>,
+    ) -> Vec<f32> {
+        let forward_area_size = forward_area_size - self.total_gap();
         let sized_children: Vec<_> = self.children.iter().enumerate()
-            .map(|(i, it)| (it, calc_size_dimension(
+            .map(|(i, it)| calc_size_dimension(
                 it,
                 dimension,
+                cross_area_size,
                 &ctx.step_down_i(i),
-            )))
+            ))
+            .collect();
+        let mut sizes: Vec<Option<f32>> = sized_children.iter()
+            .map(|size| match size {
+                CalculatedSize::Fixed(value) => Some(*value),
+                CalculatedSize::Stretch { .. } => None,
+            })
             .collect();
-        let stretch_size = {
-            let mut total_size = 0.0;
-            let mut stretch_count = 0;
-            for (_, size) in sized_children.iter().copied() {
-                match size {
-                    CalculatedSize::Fixed(value) => total_size += value,
-                    CalculatedSize::Stretch { fixed_part } => {
-                        total_size += fixed_part;
-                        stretch_count += 1;
+        // stretch children that hit their bounds are frozen there,
+        // and the space they couldn't take is shared again among the rest
+        loop {
+            let mut free_size = forward_area_size;
+            let mut total_weight = 0.0;
+            let mut total_shrink = 0.0;
+            for (size, resolved) in sized_children.iter().zip(&sizes) {
+                match (size, resolved) {
+                    (_, Some(value)) => free_size -= value,
+                    (CalculatedSize::Stretch { fixed_part, weight, .. }, None) => {
+                        free_size -= fixed_part;
+                        total_weight += weight;
+                        total_shrink += weight * fixed_part;
                     }
+                    (CalculatedSize::Fixed(_), None) => unreachable!(),
                 }
             }
-            let forward_area_size = match dimension {
-                DimensionKey::Horizontal => ctx.area.w,
-                DimensionKey::Vertical => ctx.area.h,
+            // missing space is taken from stretch children in proportion to their fixed part,
+            // so empty spacers don't go negative while content shrinks
+            let share = |fixed_part: f32, weight: f32| match free_size >= 0.0 {
+                true if total_weight > 0.0 => free_size * weight / total_weight,
+                false if total_shrink > 0.0 => free_size * weight * fixed_part / total_shrink,
+                _ => 0.0,
             };
-            (forward_area_size - total_size) / stretch_count as f32
-        };
-        let mut offset = match dimension {
-            DimensionKey::Horizontal => ctx.area.x,
-            DimensionKey::Vertical => ctx.area.y,
-        };
-        for (i, (child, size)) in sized_children.iter().copied().enumerate() {
-            let size = match size {
-                CalculatedSize::Fixed(value) => value,
-                CalculatedSize::Stretch { fixed_part } => fixed_part + stretch_size,
-            };
-            let ctx = ctx.step_down_i(i);
-            let ctx = if let Some(name) = child.get_name() {
-                ctx.step_down(name)
-            } else {
-                ctx
-            };
-            child.do_phase(ctx
-                .clone_with(|ctx| ctx.area = Rect::new(
-                    match dimension {
-                        DimensionKey::Horizontal => offset,
-                        DimensionKey::Vertical => ctx.area.x,
-                    },
-                    match dimension {
-                        DimensionKey::Horizontal => ctx.area.y,
-                        DimensionKey::Vertical => offset,
-                    },
-                    match dimension {
-                        DimensionKey::Horizontal => size,
-                        DimensionKey::Vertical => ctx.area.w,
-                    },
-                    match dimension {
-                        DimensionKey::Horizontal => ctx.area.h,
-                        DimensionKey::Vertical => size,
-                    },
-                )));
-            offset += size;
+            let mut violation = 0.0;
+            let tentative: Vec<_> = sized_children.iter().zip(&sizes)
+                .map(|(size, resolved)| match (size, resolved) {
+                    (CalculatedSize::Stretch { fixed_part, weight, min, max }, None) => {
+                        let size = fixed_part + share(*fixed_part, *weight);
+                        let clamped = clamp_size(size, *min, *max);
+                        violation += clamped - size;
+                        Some((size, clamped))
+                    }
+                    _ => None,
+                })
+                .collect();
+            if violation == 0.0 {
+                for (resolved, tentative) in sizes.iter_mut().zip(tentative) {
+                    if let Some((_, clamped)) = tentative {
+                        *resolved = Some(clamped);
+                    }
+                }
+                break;
+            }
+            for (resolved, tentative) in sizes.iter_mut().zip(tentative) {
+                if let Some((size, clamped)) = tentative {
+                    if (violation > 0.0 && clamped > size) || (violation < 0.0 && clamped < size) {
+                        *resolved = Some(clamped);
+                    }
+                }
+            }
         }
+        sizes.into_iter()
+            .map(|it| it.unwrap())
+            .collect()
     }
 }
 
-#[derive(Copy, Clone, Debug)]
-enum DimensionKey {
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum DimensionKey {
     Horizontal,
     Vertical,
 }
 
+impl DimensionKey {
+    pub(crate) fn cross(self) -> DimensionKey {
+        match self {
+            DimensionKey::Horizontal => DimensionKey::Vertical,
+            DimensionKey::Vertical => DimensionKey::Horizontal,
+        }
+    }
+
+    pub(crate) fn of(self, size: Vec2) -> f32 {
+        match self {
+            DimensionKey::Horizontal => size.x,
+            DimensionKey::Vertical => size.y,
+        }
+    }
+}
+
+impl From<DimensionKey> for Axis {
+    fn from(dimension: DimensionKey) -> Self {
+        match dimension {
+            DimensionKey::Horizontal => Axis::Horizontal,
+            DimensionKey::Vertical => Axis::Vertical,
+        }
+    }
+}
+
+impl From<Axis> for DimensionKey {
+    fn from(axis: Axis) -> Self {
+        match axis {
+            Axis::Horizontal => DimensionKey::Horizontal,
+            Axis::Vertical => DimensionKey::Vertical,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
-enum CalculatedSize {
+pub(crate) enum CalculatedSize {
     Fixed(f32),
-    Stretch { fixed_part: f32 },
+    Stretch { fixed_part: f32, weight: f32, min: Option<f32>, max: Option<f32> },
+}
+
+const SIZE_CACHE_ENTRIES: usize = 4;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SizeCache {
+    horizontal: RefCell<Vec<(SizeKey, CalculatedSize)>>,
+    vertical: RefCell<Vec<(SizeKey, CalculatedSize)>>,
+}
+
+// relative dimensions depend on the surrounding areas, not only on the cross size
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct SizeKey {
+    cross_area_size: Option<f32>,
+    area: Vec2,
+    viewport: Vec2,
+    measuring: bool,
 }
 
-fn calc_size_dimension<Event>(
+impl SizeCache {
+    fn slot(&self, dimension: DimensionKey) -> &RefCell<Vec<(SizeKey, CalculatedSize)>> {
+        match dimension {
+            DimensionKey::Horizontal => &self.horizontal,
+            DimensionKey::Vertical => &self.vertical,
+        }
+    }
+
+    fn get(&self, dimension: DimensionKey, key: SizeKey) -> Option<CalculatedSize> {
+        self.slot(dimension).borrow().iter()
+            .find(|(it, _)| *it == key)
+            .map(|(_, size)| *size)
+    }
+
+    fn put(&self, dimension: DimensionKey, key: SizeKey, size: CalculatedSize) {
+        let mut entries = self.slot(dimension).borrow_mut();
+        if entries.len() >= SIZE_CACHE_ENTRIES {
+            entries.remove(0);
+        }
+        entries.push((key, size));
+    }
+}
+
+pub(crate) fn calc_size_dimension<Event>(
     node: &Node<Event>,
     dimension: DimensionKey,
+    cross_area_size: Option<f32>,
     ctx: &Ctx<Event>,
 ) -> CalculatedSize
     where Event: Clone + Debug + 'static
 {
+    let key = SizeKey { cross_area_size, area: ctx.area.size(), viewport: ctx.viewport.size(), measuring: ctx.measuring };
+    if let Some(size) = node.size_cache.get(dimension, key) {
+        return size;
+    }
+    let size = measure_size_dimension(node, dimension, cross_area_size, ctx);
+    node.size_cache.put(dimension, key, size);
+    size
+}
+
+fn measure_size_dimension<Event>(
+    node: &Node<Event>,
+    dimension: DimensionKey,
+    cross_area_size: Option<f32>,
+    ctx: &Ctx<Event>,
+) -> CalculatedSize
+    where Event: Clone + Debug + 'static
+{
+    if node.is_hidden() {
+        return CalculatedSize::Fixed(0.0);
+    }
+    if let Some(size) = aspect_size(node, dimension, cross_area_size, ctx) {
+        return size;
+    }
     let ctx = ctx.clone();
     let ctx = if let Some(name) = node.get_name() {
         ctx.step_down(name)
     } else {
         ctx
     };
-    let dimension_value = match dimension {
-        DimensionKey::Horizontal => node.unique::<Width>().map(|it| it.0),
-        DimensionKey::Vertical => node.unique::<Height>().map(|it| it.0),
+    let (dimension_value, bounds) = match dimension {
+        DimensionKey::Horizontal => node.unique::<Width>().map_or((None, Bounds::default()), |it| (Some(it.0), it.1)),
+        DimensionKey::Vertical => node.unique::<Height>().map_or((None, Bounds::default()), |it| (Some(it.0), it.1)),
     };
-    let flow = match dimension_value {
-        None => Flow::Calculate(CalculateFlow::AsIs),
-        Some(size) => match size {
+    let flow = match (dimension_value, node.unique::<Placement>()) {
+        (None, Some(Placement::Absolute(rect))) => Flow::Propagate(CalculatedSize::Fixed(dimension.of(rect.size()))),
+        (None, _) => Flow::Calculate(CalculateFlow::AsIs),
+        (Some(size), _) => match size {
             Dimension::Fixed(size) => Flow::Propagate(CalculatedSize::Fixed(size)),
-            Dimension::Stretch { fixed_part } => Flow::Propagate(CalculatedSize::Stretch { fixed_part }),
+            // the parent area a percentage refers to isn't known while the parent measures its content
+            Dimension::Percent(_) if ctx.measuring => Flow::Propagate(CalculatedSize::stretch(0.0, 1.0)),
+            Dimension::Percent(percent) => Flow::Propagate(CalculatedSize::Fixed(dimension.of(ctx.area.size()) * percent / 100.0)),
+            Dimension::ViewportWidth(percent) => Flow::Propagate(CalculatedSize::Fixed(ctx.viewport.w * percent / 100.0)),
+            Dimension::ViewportHeight(percent) => Flow::Propagate(CalculatedSize::Fixed(ctx.viewport.h * percent / 100.0)),
+            Dimension::Stretch { fixed_part, weight } => Flow::Propagate(CalculatedSize::stretch(fixed_part, weight)),
             Dimension::RemoveStretch => Flow::Calculate(CalculateFlow::RemoveStretch),
+            Dimension::Shrink => Flow::Calculate(CalculateFlow::Shrink),
         },
     };
     enum CalculateFlow {
         AsIs,
         RemoveStretch,
+        Shrink,
     }
     enum Flow {
         Propagate(CalculatedSize),
         Calculate(CalculateFlow),
     }
-    match flow {
+    let size = match flow {
         Flow::Propagate(size) => size,
         Flow::Calculate(sub_flow) => {
-            match node.unique::<Group<Event>>() {
-                None => panic!(
-                    "failed to resolve {:?} size of '{}' ({})",
-                    dimension,
-                    node.get_name().unwrap_or("unknown"),
-                    ctx.backtrace(),
-                ),
+            let content_size = match node.unique::<Group<Event>>() {
+                None => match node.measure(dimension.into(), cross_area_size, ctx.clone()) {
+                    Some(size) => CalculatedSize::Fixed(size),
+                    None => panic!(
+                        "failed to resolve {:?} size of '{}' ({})",
+                        dimension,
+                        node.get_name().unwrap_or("unknown"),
+                        ctx.backtrace(),
+                    ),
+                },
                 Some(group) => {
+                    let ctx = ctx.clone_with(|ctx| ctx.measuring = true);
                     let merge_strategy = match dimension {
                         DimensionKey::Horizontal => match group.layout {
                             Layout::Layered => CalculatedSize::max,
                             Layout::Vertical => CalculatedSize::max,
                             Layout::Horizontal => CalculatedSize::sum,
+                            Layout::Grid(_) => CalculatedSize::sum,
+                            Layout::Wrap(_) => CalculatedSize::sum,
+                            Layout::Scroll(_) => CalculatedSize::sum,
                         }
                         DimensionKey::Vertical => match group.layout {
                             Layout::Layered => CalculatedSize::max,
                             Layout::Vertical => CalculatedSize::sum,
                             Layout::Horizontal => CalculatedSize::max,
+                            Layout::Grid(_) => CalculatedSize::sum,
+                            Layout::Wrap(_) => CalculatedSize::sum,
+                            Layout::Scroll(_) => CalculatedSize::sum,
                         }
                     };
-                    let final_size = group.children.iter().enumerate()
-                        .map(|(i, it)| calc_size_dimension(
-                            it, dimension,
-                            &ctx.step_down_i(i),
-                        ))
-                        .reduce(merge_strategy)
-                        .unwrap_or(CalculatedSize::Stretch { fixed_part: 0.0 });
-                    match sub_flow {
-                        CalculateFlow::AsIs => final_size,
-                        CalculateFlow::RemoveStretch => CalculatedSize::Fixed(final_size.get_fixed_part()),
+                    let group_dimension = match group.layout {
+                        Layout::Vertical => Some(DimensionKey::Vertical),
+                        Layout::Horizontal => Some(DimensionKey::Horizontal),
+                        _ => None,
+                    };
+                    let children_cross_sizes: Vec<_> = match (group_dimension, cross_area_size) {
+                        (Some(group_dimension), Some(cross_area_size)) if group_dimension != dimension => group
+                            .distribute(&ctx, group_dimension, cross_area_size, None)
+                            .into_iter()
+                            .map(Some)
+                            .collect(),
+                        _ => vec![cross_area_size; group.children.len()],
+                    };
+                    match &group.layout {
+                        Layout::Grid(grid) => grid.measure(&group.children, dimension, cross_area_size, &ctx),
+                        Layout::Wrap(axis) => wrap::measure(&group.children, (*axis).into(), group.gap, dimension, cross_area_size, &ctx),
+                        Layout::Scroll(axis) => scroll::measure(&group.children, (*axis).into(), dimension, cross_area_size, &ctx),
+                        _ => {
+                            let line_height = match (&group.layout, dimension) {
+                                (Layout::Horizontal, DimensionKey::Vertical) => group.baseline_line_height(&ctx, &children_cross_sizes),
+                                _ => None,
+                            };
+                            let size = group.children.iter().zip(children_cross_sizes).enumerate()
+                                .map(|(i, (it, cross_area_size))| calc_size_dimension(
+                                    it, dimension,
+                                    cross_area_size,
+                                    &ctx.step_down_i(i),
+                                ))
+                                .reduce(merge_strategy)
+                                .unwrap_or(CalculatedSize::stretch(0.0, 1.0));
+                            if group_dimension == Some(dimension) {
+                                CalculatedSize::sum(size, CalculatedSize::Fixed(group.total_gap()))
+                            } else if let Some(line_height) = line_height {
+                                CalculatedSize::max(size, CalculatedSize::Fixed(line_height))
+                            } else {
+                                size
+                            }
+                        }
                     }
                 }
+            };
+            match sub_flow {
+                CalculateFlow::AsIs => content_size,
+                CalculateFlow::RemoveStretch => CalculatedSize::Fixed(content_size.get_fixed_part()),
+                // prefers its content size but gives it up when the space runs out
+                CalculateFlow::Shrink => CalculatedSize::Stretch {
+                    fixed_part: content_size.get_fixed_part(),
+                    weight: 1.0,
+                    min: Some(0.0),
+                    max: Some(content_size.get_fixed_part()),
+                },
             }
         }
+    };
+    bounds.apply(size)
+}
+
+// a node with an aspect ratio takes the size of an unset axis from the other one,
+// and stretches when the other one isn't known yet
+fn aspect_size<Event>(
+    node: &Node<Event>,
+    dimension: DimensionKey,
+    cross_area_size: Option<f32>,
+    ctx: &Ctx<Event>,
+) -> Option<CalculatedSize>
+    where Event: Clone + Debug + 'static
+{
+    let AspectRatio(ratio) = *node.unique::<AspectRatio>()?;
+    let is_set = |dimension| match dimension {
+        DimensionKey::Horizontal => node.unique::<Width>().is_some(),
+        DimensionKey::Vertical => node.unique::<Height>().is_some(),
+    };
+    if is_set(dimension) {
+        return None;
+    }
+    let cross_size = match (is_set(dimension.cross()), cross_area_size) {
+        (true, cross_area_size) => match calc_size_dimension(node, dimension.cross(), None, ctx) {
+            CalculatedSize::Fixed(size) => Some(size),
+            CalculatedSize::Stretch { min, max, .. } => cross_area_size.map(|it| clamp_size(it, min, max)),
+        },
+        (false, cross_area_size) => cross_area_size,
+    };
+    Some(match (cross_size, dimension) {
+        (None, _) => CalculatedSize::stretch(0.0, 1.0),
+        (Some(size), DimensionKey::Horizontal) => CalculatedSize::Fixed(size * ratio),
+        (Some(size), DimensionKey::Vertical) => CalculatedSize::Fixed(size / ratio),
+    })
+}
+
+// the cross size of a node with an aspect ratio follows from its main size instead of stretching
+fn has_aspect_cross<Event: Clone + Debug + 'static>(node: &Node<Event>, cross: DimensionKey) -> bool {
+    node.unique::<AspectRatio>().is_some() && match cross {
+        DimensionKey::Horizontal => node.unique::<Width>().is_none(),
+        DimensionKey::Vertical => node.unique::<Height>().is_none(),
+    }
+}
+
+pub(crate) fn clamp_size(size: f32, min: Option<f32>, max: Option<f32>) -> f32 {
+    let size = max.map_or(size, |max| size.min(max));
+    min.map_or(size, |min| size.max(min))
+}
+
+fn merge_bound(a: Option<f32>, b: Option<f32>, f: fn(f32, f32) -> f32) -> Option<f32> {
+    match (a, b) {
+        (None, None) => None,
+        (a, b) => Some(f(a.unwrap_or(0.0), b.unwrap_or(0.0))),
     }
 }
 
 impl CalculatedSize {
+    pub(crate) fn stretch(fixed_part: f32, weight: f32) -> CalculatedSize {
+        CalculatedSize::Stretch { fixed_part, weight, min: None, max: None }
+    }
+
+    // a group competes for free space with the weight of its heaviest stretching child,
+    // so nesting stretch nodes in a group doesn't make the group greedier than its content
     fn sum(a: CalculatedSize, b: CalculatedSize) -> CalculatedSize {
         let fixed_part = a.get_fixed_part() + b.get_fixed_part();
         if a.is_stretch() || b.is_stretch() {
-            CalculatedSize::Stretch { fixed_part }
+            CalculatedSize::Stretch {
+                fixed_part,
+                weight: a.get_weight().max(b.get_weight()),
+                min: merge_bound(a.get_min(), b.get_min(), |a, b| a + b),
+                max: a.get_max().zip(b.get_max()).map(|(a, b)| a + b),
+            }
         } else {
             CalculatedSize::Fixed(fixed_part)
         }
@@ -229,12 +758,31 @@ impl CalculatedSize {
     fn max(a: CalculatedSize, b: CalculatedSize) -> CalculatedSize {
         let fixed_part = a.get_fixed_part().max(b.get_fixed_part());
         if a.is_stretch() || b.is_stretch() {
-            CalculatedSize::Stretch { fixed_part }
+            CalculatedSize::Stretch {
+                fixed_part,
+                weight: a.get_weight().max(b.get_weight()),
+                min: merge_bound(a.get_min(), b.get_min(), f32::max),
+                max: a.get_max().zip(b.get_max()).map(|(a, b)| a.max(b)),
+            }
         } else {
             CalculatedSize::Fixed(fixed_part)
         }
     }
 
+    fn get_min(&self) -> Option<f32> {
+        match self {
+            CalculatedSize::Fixed(value) => Some(*value),
+            CalculatedSize::Stretch { min, .. } => *min,
+        }
+    }
+
+    fn get_max(&self) -> Option<f32> {
+        match self {
+            CalculatedSize::Fixed(value) => Some(*value),
+            CalculatedSize::Stretch { max, .. } => *max,
+        }
+    }
+
     fn is_stretch(&self) -> bool {
         match self {
             CalculatedSize::Fixed(_) => false,
@@ -242,10 +790,17 @@ impl CalculatedSize {
         }
     }
 
-    fn get_fixed_part(&self) -> f32 {
+    pub(crate) fn get_fixed_part(&self) -> f32 {
         *match self {
             CalculatedSize::Fixed(value) => value,
-            CalculatedSize::Stretch { fixed_part } => fixed_part
+            CalculatedSize::Stretch { fixed_part, .. } => fixed_part
+        }
+    }
+
+    fn get_weight(&self) -> f32 {
+        match self {
+            CalculatedSize::Fixed(_) => 0.0,
+            CalculatedSize::Stretch { weight, .. } => *weight,
         }
     }
 }
\ No newline at end of file
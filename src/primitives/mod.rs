@@ -1,17 +1,32 @@
-use macroquad::color::Color;
+use macroquad::color::{Color, WHITE};
+use macroquad::texture::Texture2D;
+use macroquad::math::{Rect, Vec2};
+use crate::primitives::anchor::{Anchor, Placement};
+use crate::primitives::clip::Clip;
 use crate::primitives::color_fill::ColorFill;
-use crate::primitives::group::{Dimension, Group, Height, Layout, Width};
+use crate::primitives::grid::{Grid, GridSpan, Track};
+use crate::primitives::group::{Align, AlignSelf, AspectRatio, Axis, Dimension, Group, Height, Layout, Width};
 use crate::primitives::node::Node;
+use crate::primitives::rich_text::Span;
+use crate::primitives::scroll::ScrollHandler;
 use crate::primitives::text::{Text, TextStyle};
 
+pub mod anchor;
+pub mod clip;
 pub mod group;
+pub mod scroll;
+pub mod grid;
+mod wrap;
 pub mod text;
+pub mod paragraph;
+pub mod rich_text;
 pub mod mouse;
 pub mod color_fill;
 pub mod border;
 pub mod conditional;
 pub mod node;
 pub mod texture;
+pub mod virtual_list;
 
 
 pub fn color_fill(color: Color) -> ColorFill {
@@ -19,27 +34,111 @@ pub fn color_fill(color: Color) -> ColorFill {
 }
 
 pub fn width(value: f32) -> Width {
-    Width(Dimension::Fixed(value))
+    Width::new(Dimension::Fixed(value))
+}
+
+pub fn width_percent(percent: f32) -> Width {
+    Width::new(Dimension::Percent(percent))
+}
+
+pub fn width_vw(percent: f32) -> Width {
+    Width::new(Dimension::ViewportWidth(percent))
+}
+
+pub fn width_vh(percent: f32) -> Width {
+    Width::new(Dimension::ViewportHeight(percent))
 }
 
 pub fn width_stretch() -> Width {
-    Width(Dimension::Stretch { fixed_part: 0.0 })
+    width_stretch_weighted(1.0)
+}
+
+pub fn width_stretch_weighted(weight: f32) -> Width {
+    Width::new(Dimension::Stretch { fixed_part: 0.0, weight })
+}
+
+pub fn width_min(value: f32) -> Width {
+    width_stretch().min(value)
+}
+
+pub fn width_max(value: f32) -> Width {
+    width_stretch().max(value)
+}
+
+pub fn width_clamped(min: f32, max: f32) -> Width {
+    width_stretch().min(min).max(max)
 }
 
 pub fn width_no_stretch() -> Width {
-    Width(Dimension::RemoveStretch)
+    Width::new(Dimension::RemoveStretch)
+}
+
+pub fn width_shrink() -> Width {
+    Width::new(Dimension::Shrink)
 }
 
 pub fn height(value: f32) -> Height {
-    Height(Dimension::Fixed(value))
+    Height::new(Dimension::Fixed(value))
+}
+
+pub fn height_percent(percent: f32) -> Height {
+    Height::new(Dimension::Percent(percent))
+}
+
+pub fn height_vw(percent: f32) -> Height {
+    Height::new(Dimension::ViewportWidth(percent))
+}
+
+pub fn height_vh(percent: f32) -> Height {
+    Height::new(Dimension::ViewportHeight(percent))
 }
 
 pub fn height_stretch() -> Height {
-    Height(Dimension::Stretch { fixed_part: 0.0 })
+    height_stretch_weighted(1.0)
+}
+
+pub fn height_stretch_weighted(weight: f32) -> Height {
+    Height::new(Dimension::Stretch { fixed_part: 0.0, weight })
+}
+
+pub fn height_min(value: f32) -> Height {
+    height_stretch().min(value)
+}
+
+pub fn height_max(value: f32) -> Height {
+    height_stretch().max(value)
+}
+
+pub fn height_clamped(min: f32, max: f32) -> Height {
+    height_stretch().min(min).max(max)
 }
 
 pub fn height_no_stretch() -> Height {
-    Height(Dimension::RemoveStretch)
+    Height::new(Dimension::RemoveStretch)
+}
+
+pub fn height_shrink() -> Height {
+    Height::new(Dimension::Shrink)
+}
+
+pub fn anchor(anchor: Anchor, offset: Vec2) -> Placement {
+    Placement::Anchored { anchor, offset }
+}
+
+pub fn absolute(rect: Rect) -> Placement {
+    Placement::Absolute(rect)
+}
+
+pub fn clip() -> Clip {
+    Clip
+}
+
+pub fn aspect_ratio(ratio: f32) -> AspectRatio {
+    AspectRatio(ratio)
+}
+
+pub fn align_self(align: Align) -> AlignSelf {
+    AlignSelf(align)
 }
 
 pub fn layers<Event: Clone, T: Into<Vec<Node<Event>>>>(children: T) -> Group<Event> {
@@ -54,10 +153,42 @@ pub fn vertical_content<Event: Clone, T: Into<Vec<Node<Event>>>>(children: T) ->
     Group::new(Layout::Vertical, children.into())
 }
 
+pub fn horizontal_wrap_content<Event: Clone, T: Into<Vec<Node<Event>>>>(children: T) -> Group<Event> {
+    Group::new(Layout::Wrap(Axis::Horizontal), children.into())
+}
+
+pub fn vertical_wrap_content<Event: Clone, T: Into<Vec<Node<Event>>>>(children: T) -> Group<Event> {
+    Group::new(Layout::Wrap(Axis::Vertical), children.into())
+}
+
+pub fn scroll_content<Event: Clone, T: Into<Vec<Node<Event>>>>(axis: Axis, children: T) -> Group<Event> {
+    Group::new(Layout::Scroll(axis), children.into())
+}
+
+pub fn scroll(axis: Axis) -> ScrollHandler {
+    ScrollHandler::new(axis)
+}
+
+pub fn grid_content<Event: Clone, C: Into<Vec<Track>>, T: Into<Vec<Node<Event>>>>(columns: C, children: T) -> Group<Event> {
+    Group::new(Layout::Grid(Grid::new(columns)), children.into())
+}
+
+pub fn grid_span(columns: usize, rows: usize) -> GridSpan {
+    GridSpan { columns, rows }
+}
+
 pub fn single_content<Event>(child: Node<Event>) -> Group<Event> {
     Group::new(Layout::Horizontal, vec![child])
 }
 
 pub fn text<S: Into<String>>(value: S, style: TextStyle) -> Text {
     Text { value: value.into(), style }
+}
+
+pub fn span<S: Into<String>, T: Into<TextStyle>>(value: S, style: T) -> Span {
+    Span::Text(value.into(), style.into())
+}
+
+pub fn inline_icon(texture: Texture2D, size: Vec2) -> Span {
+    Span::Icon { texture, size, color: WHITE }
 }
\ No newline at end of file
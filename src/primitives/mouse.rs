@@ -1,7 +1,6 @@
 use std::fmt::Debug;
 
-use macroquad::input::{is_mouse_button_down, is_mouse_button_pressed, mouse_position, MouseButton};
-use macroquad::math::Vec2;
+use macroquad::input::MouseButton;
 
 use crate::core::{Ctx, Element, Phase};
 
@@ -22,16 +21,15 @@ pub struct MouseHoverHandler<Event: Debug + Clone> {
 
 impl<Event: Debug + Clone> Element<Event> for MouseButtonHandler<Event> {
     fn do_phase(&self, ctx: Ctx<Event>) {
-        let area = ctx.area;
         match ctx.phase {
-            Phase::Draw { .. } => {}
-            Phase::CollectEvents { collected } => {
+            Phase::Layout | Phase::Draw { .. } => {}
+            Phase::CollectEvents { collected, input } => {
                 let mut hits = None;
                 for (button, event_id) in self.on_click.clone() {
                     if hits.is_none() {
-                        hits = Some(area.contains(Vec2::from(mouse_position())));
+                        hits = Some(ctx.hits(input.mouse_position));
                     }
-                    if hits.unwrap() && is_mouse_button_pressed(button) {
+                    if hits.unwrap() && input.is_mouse_button_pressed(button) {
                         collected.borrow_mut().push(event_id);
                     }
                 }
@@ -42,16 +40,15 @@ impl<Event: Debug + Clone> Element<Event> for MouseButtonHandler<Event> {
 
 impl<Event: Debug + Clone> Element<Event> for MouseButtonPressedHandler<Event> {
     fn do_phase(&self, ctx: Ctx<Event>) {
-        let area = ctx.area;
         match ctx.phase {
-            Phase::Draw { .. } => {}
-            Phase::CollectEvents { collected } => {
+            Phase::Layout | Phase::Draw { .. } => {}
+            Phase::CollectEvents { collected, input } => {
                 let mut hits = None;
                 for (button, event_id) in self.on_pressed.clone() {
                     if hits.is_none() {
-                        hits = Some(area.contains(Vec2::from(mouse_position())));
+                        hits = Some(ctx.hits(input.mouse_position));
                     }
-                    if hits.unwrap() && is_mouse_button_down(button) {
+                    if hits.unwrap() && input.is_mouse_button_down(button) {
                         collected.borrow_mut().push(event_id);
                     }
                 }
@@ -62,13 +59,12 @@ impl<Event: Debug + Clone> Element<Event> for MouseButtonPressedHandler<Event> {
 
 impl<Event: Debug + Clone> Element<Event> for MouseHoverHandler<Event> {
     fn do_phase(&self, ctx: Ctx<Event>) {
-        let area = ctx.area;
         match ctx.phase {
-            Phase::Draw { .. } => {}
-            Phase::CollectEvents { collected } => {
+            Phase::Layout | Phase::Draw { .. } => {}
+            Phase::CollectEvents { collected, input } => {
                 let mut hits = None;
                 if hits.is_none() {
-                    hits = Some(area.contains(Vec2::from(mouse_position())));
+                    hits = Some(ctx.hits(input.mouse_position));
                 }
                 if hits.unwrap() {
                     collected.borrow_mut().push(self.on_hover.clone());
@@ -1,6 +1,9 @@
 use std::fmt::Debug;
 use std::mem;
-use crate::core::{Ctx, Element};
+use crate::core::{Ctx, Element, Phase};
+use crate::primitives::clip::{clip_rect, Clip};
+use crate::layout::LayoutNode;
+use crate::primitives::group::{Axis, Group, SizeCache};
 use crate::{make_bounded_any_box, make_bounded_composite};
 
 make_bounded_composite! {, ComponentSet<Event>, Element<Event>}
@@ -9,8 +12,11 @@ make_bounded_any_box!{,NodePaddingBox<Event>, NodePadding<Event>}
 #[derive(Debug, Clone)]
 pub struct Node<Event> {
     name: Option<&'static str>,
+    key: Option<String>,
+    hidden: bool,
     components: ComponentSet<Event>,
     paddings: Vec<NodePaddingBox<Event>>,
+    pub(crate) size_cache: SizeCache,
 }
 
 pub trait NodePadding<Event> {
@@ -24,17 +30,29 @@ impl<Event> Node<Event> {
             if candidates.len() > 1 {
                 panic!("failed to resolve single component");
             }
-            return candidates.get(0).copied();
+            return candidates.first().copied();
         }
         None
     }
+
+    pub(crate) fn unique_mut<T: 'static + Element<Event>>(&mut self) -> Option<&mut T> {
+        let mut candidates = self.components.get_mut::<T>()?;
+        let first = candidates.next();
+        if candidates.next().is_some() {
+            panic!("failed to resolve single component");
+        }
+        first
+    }
 }
 
 pub fn node<Event>() -> Node<Event> {
     Node {
         name: None,
+        key: None,
+        hidden: false,
         components: ComponentSet::new(),
         paddings: vec![],
+        size_cache: Default::default(),
     }
 }
 
@@ -43,6 +61,7 @@ impl<Event: Clone> Node<Event> {
     pub fn set<T: Element<Event> + Clone + Debug + 'static>(mut self, component: T) -> Self {
         let padded = component.expand_padding();
         self.components.insert(padded);
+        self.size_cache = Default::default();
         self
     }
 
@@ -56,6 +75,40 @@ impl<Event: Clone> Node<Event> {
         self
     }
 
+    pub fn key<K: Into<String>>(mut self, key: K) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    pub fn replace<T: Element<Event> + Clone + Debug + 'static>(&mut self, component: T) {
+        self.components.remove::<T>();
+        self.components.insert(component.expand_padding());
+        self.size_cache = Default::default();
+    }
+
+    pub fn remove<T: Element<Event> + 'static>(&mut self) {
+        self.components.remove::<T>();
+        self.size_cache = Default::default();
+    }
+
+    pub fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+        self.size_cache = Default::default();
+    }
+
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    pub fn get_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
     pub(crate) fn expand_padding(self) -> Self {
         let mut node = self;
         let paddings = mem::take(&mut node.paddings);
@@ -66,12 +119,104 @@ impl<Event: Clone> Node<Event> {
     }
 
     pub(crate) fn do_phase(&self, ctx: Ctx<Event>) {
+        if self.hidden {
+            return;
+        }
+        if self.unique::<Clip>().is_none() {
+            for feature in self.components.iter() {
+                feature.do_phase(ctx.clone());
+            }
+            return;
+        }
+        let clip = clip_rect(&ctx);
+        let clipped = ctx.clone_with(|ctx| ctx.clip = Some(clip));
+        if let Phase::Draw { renderer, .. } = ctx.phase {
+            renderer.set_clip(Some(clip));
+        }
         for feature in self.components.iter() {
-            feature.do_phase(ctx.clone());
+            feature.do_phase(clipped.clone());
         }
+        if let Phase::Draw { renderer, .. } = ctx.phase {
+            renderer.set_clip(ctx.clip);
+        }
+    }
+
+    pub(crate) fn layout(&self, ctx: Ctx<Event>) -> LayoutNode {
+        let ctx = match self.unique::<Clip>() {
+            Some(_) => ctx.clone_with(|it| it.clip = Some(clip_rect(&ctx))),
+            None => ctx,
+        };
+        LayoutNode {
+            name: self.name,
+            path: ctx.path.to_path(),
+            rect: ctx.area,
+            children: if self.hidden {
+                vec![]
+            } else {
+                self.components.iter()
+                    .flat_map(|it| it.layout(ctx.clone()))
+                    .collect()
+            },
+        }
+    }
+
+    pub(crate) fn measure(&self, axis: Axis, cross_area_size: Option<f32>, ctx: Ctx<Event>) -> Option<f32> {
+        self.components.iter().find_map(|it| it.measure(axis, cross_area_size, ctx.clone()))
+    }
+
+    pub(crate) fn baseline(&self, ctx: Ctx<Event>) -> Option<f32> {
+        if self.hidden {
+            return None;
+        }
+        self.components.iter().find_map(|it| it.baseline(ctx.clone()))
     }
 
     pub(crate) fn get_name(&self) -> Option<&'static str> {
         self.name
     }
 }
+
+impl<Event: Clone + Debug + 'static> Node<Event> {
+    pub(crate) fn route_to_path(&self, path: &str) -> Option<Vec<usize>> {
+        let mut segments = path.split('/').skip(1).peekable();
+        if segments.next()? != "root" {
+            return None;
+        }
+        let mut route = vec![];
+        let mut node = self;
+        while let Some(segment) = segments.next() {
+            let index: usize = segment.parse().ok()?;
+            let child = node.unique::<Group<Event>>()?.children().get(index)?;
+            if let Some(name) = child.get_name() {
+                if segments.peek() == Some(&name) {
+                    segments.next();
+                }
+            }
+            route.push(index);
+            node = child;
+        }
+        Some(route)
+    }
+
+    pub(crate) fn route_to_key(&self, key: &str) -> Option<Vec<usize>> {
+        if self.get_key() == Some(key) {
+            return Some(vec![]);
+        }
+        self.unique::<Group<Event>>()?.children().iter().enumerate()
+            .find_map(|(i, child)| child.route_to_key(key).map(|mut route| {
+                route.insert(0, i);
+                route
+            }))
+    }
+
+    pub(crate) fn descend_mut(&mut self, route: &[usize]) -> &mut Node<Event> {
+        self.size_cache = Default::default();
+        match route.split_first() {
+            None => self,
+            Some((index, rest)) => self.unique_mut::<Group<Event>>()
+                .expect("route must follow group children")
+                .children_mut()[*index]
+                .descend_mut(rest),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::core::{Ctx, Element, Phase};
+use crate::primitives::group::Axis;
+use crate::primitives::text::TextStyle;
+use crate::render::Renderer;
+
+#[derive(Debug, Clone)]
+pub struct Paragraph {
+    pub value: String,
+    pub style: TextStyle,
+}
+
+impl<Event> Element<Event> for Paragraph {
+    fn do_phase(&self, ctx: Ctx<Event>) {
+        match ctx.phase {
+            Phase::Draw { renderer, .. } => {
+                let measurer = ctx.renderer;
+                for (i, line) in self.lines(measurer, Some(ctx.area.w)).iter().enumerate() {
+                    let y = ctx.area.y + i as f32 * self.style.line_height(measurer) + self.style.baseline_offset(measurer);
+                    self.style.draw_in(measurer, renderer, ctx.clip, line, ctx.area, y);
+                }
+            }
+            Phase::Layout | Phase::CollectEvents { .. } => {}
+        }
+    }
+
+    fn baseline(&self, ctx: Ctx<Event>) -> Option<f32> {
+        Some(self.style.baseline_offset(ctx.renderer))
+    }
+
+    fn measure(&self, axis: Axis, cross_area_size: Option<f32>, ctx: Ctx<Event>) -> Option<f32> {
+        let measurer = ctx.renderer;
+        Some(match axis {
+            Axis::Horizontal => self.lines(measurer, None).iter()
+                .map(|it| self.style.measure(measurer, it).width)
+                .fold(0.0, f32::max),
+            Axis::Vertical => self.lines(measurer, cross_area_size).len() as f32 * self.style.line_height(measurer),
+        })
+    }
+}
+
+impl Paragraph {
+    pub fn lines(&self, measurer: &dyn Renderer, max_width: Option<f32>) -> Vec<String> {
+        wrap_lines(&self.value, max_width, |it| self.style.measure(measurer, it).width)
+    }
+}
+
+// greedy word wrap; a word wider than the line is kept whole on its own line
+pub(crate) fn wrap_lines<F: Fn(&str) -> f32>(text: &str, max_width: Option<f32>, measure: F) -> Vec<String> {
+    let mut lines = vec![];
+    for hard_line in text.split('\n') {
+        let Some(max_width) = max_width else {
+            lines.push(hard_line.to_owned());
+            continue;
+        };
+        let mut line = String::new();
+        for word in hard_line.split_whitespace() {
+            if line.is_empty() {
+                line.push_str(word);
+                continue;
+            }
+            let candidate = format!("{} {}", line, word);
+            if measure(&candidate) > max_width {
+                lines.push(line);
+                line = word.to_owned();
+            } else {
+                line = candidate;
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
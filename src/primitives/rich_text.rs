@@ -0,0 +1,192 @@
+use macroquad::color::Color;
+use macroquad::math::{Rect, Vec2, vec2};
+use macroquad::texture::Texture2D;
+
+use crate::core::{Ctx, Element, Phase};
+use crate::primitives::group::Axis;
+use crate::primitives::text::{TextAlign, TextStyle};
+use crate::render::Renderer;
+
+// a line is aligned by its first text span's style; spans never overflow since lines wrap instead
+#[derive(Debug, Clone)]
+pub enum Span {
+    Text(String, TextStyle),
+    Icon { texture: Texture2D, size: Vec2, color: Color },
+}
+
+#[derive(Debug, Clone)]
+pub struct RichText {
+    pub spans: Vec<Span>,
+}
+
+#[derive(Debug, Clone)]
+enum PieceKind<'a> {
+    Text(&'a str, &'a TextStyle),
+    Icon(Texture2D, Vec2, Color),
+    Break,
+}
+
+#[derive(Debug, Clone)]
+struct Piece<'a> {
+    kind: PieceKind<'a>,
+    // width without trailing whitespace, used to decide whether the piece still fits
+    width: f32,
+    advance: f32,
+    ascent: f32,
+    descent: f32,
+    line_height: f32,
+}
+
+#[derive(Debug, Clone)]
+struct Line<'a> {
+    pieces: Vec<Piece<'a>>,
+    ascent: f32,
+    height: f32,
+}
+
+impl<Event> Element<Event> for RichText {
+    fn do_phase(&self, ctx: Ctx<Event>) {
+        match ctx.phase {
+            Phase::Draw { renderer, .. } => {
+                let mut y = ctx.area.y;
+                for line in self.lines(ctx.renderer, Some(ctx.area.w)) {
+                    let x = match line.align() {
+                        TextAlign::Left => ctx.area.x,
+                        TextAlign::Center => ctx.area.x + (ctx.area.w - line.width()) / 2.0,
+                        TextAlign::Right => ctx.area.x + ctx.area.w - line.width(),
+                    };
+                    draw_line(renderer, &line, vec2(x, y + line.ascent));
+                    y += line.height;
+                }
+            }
+            Phase::Layout | Phase::CollectEvents { .. } => {}
+        }
+    }
+
+    fn baseline(&self, ctx: Ctx<Event>) -> Option<f32> {
+        self.lines(ctx.renderer, None).first().map(|it| it.ascent)
+    }
+
+    fn measure(&self, axis: Axis, cross_area_size: Option<f32>, ctx: Ctx<Event>) -> Option<f32> {
+        Some(match axis {
+            Axis::Horizontal => self.lines(ctx.renderer, None).iter()
+                .map(|line| line.pieces.iter().map(|it| it.advance).sum::<f32>())
+                .fold(0.0, f32::max),
+            Axis::Vertical => self.lines(ctx.renderer, cross_area_size).iter().map(|it| it.height).sum(),
+        })
+    }
+}
+
+impl RichText {
+    fn pieces(&self, measurer: &dyn Renderer) -> Vec<Piece<'_>> {
+        let mut pieces = vec![];
+        for span in &self.spans {
+            match span {
+                Span::Text(text, style) => {
+                    let metrics = style.metrics(measurer);
+                    let line_height = style.line_height(measurer);
+                    for (i, hard_line) in text.split('\n').enumerate() {
+                        if i > 0 {
+                            pieces.push(Piece {
+                                kind: PieceKind::Break,
+                                width: 0.0,
+                                advance: 0.0,
+                                ascent: metrics.ascent,
+                                descent: metrics.descent,
+                                line_height,
+                            });
+                        }
+                        for word in hard_line.split_inclusive(' ') {
+                            pieces.push(Piece {
+                                kind: PieceKind::Text(word, style),
+                                width: style.measure(measurer, word.trim_end()).width,
+                                advance: style.measure(measurer, word).width,
+                                ascent: metrics.ascent,
+                                descent: metrics.descent,
+                                line_height,
+                            });
+                        }
+                    }
+                }
+                Span::Icon { texture, size, color } => pieces.push(Piece {
+                    kind: PieceKind::Icon(*texture, *size, *color),
+                    width: size.x,
+                    advance: size.x,
+                    ascent: size.y,
+                    descent: 0.0,
+                    line_height: size.y,
+                }),
+            }
+        }
+        pieces
+    }
+
+    // greedy wrap over words and icons; everything on a line shares one baseline
+    fn lines(&self, measurer: &dyn Renderer, max_width: Option<f32>) -> Vec<Line<'_>> {
+        let mut lines = vec![];
+        let mut current: Vec<Piece> = vec![];
+        let mut position = 0.0;
+        for piece in self.pieces(measurer) {
+            if let PieceKind::Break = piece.kind {
+                current.push(piece);
+                lines.push(Line::new(current));
+                current = vec![];
+                position = 0.0;
+                continue;
+            }
+            let overflows = max_width.is_some_and(|max_width| position + piece.width > max_width);
+            if overflows && current.iter().any(|it| it.advance > 0.0) {
+                lines.push(Line::new(current));
+                current = vec![];
+                position = 0.0;
+            }
+            position += piece.advance;
+            current.push(piece);
+        }
+        if !current.is_empty() || lines.is_empty() {
+            lines.push(Line::new(current));
+        }
+        lines
+    }
+}
+
+impl<'a> Line<'a> {
+    fn new(pieces: Vec<Piece<'a>>) -> Line<'a> {
+        let ascent = pieces.iter().map(|it| it.ascent).fold(0.0, f32::max);
+        let descent = pieces.iter().map(|it| it.descent).fold(0.0, f32::max);
+        let line_height = pieces.iter().map(|it| it.line_height).fold(0.0, f32::max);
+        Line { pieces, ascent, height: line_height.max(ascent + descent) }
+    }
+
+    // trailing whitespace doesn't count towards the aligned width
+    fn width(&self) -> f32 {
+        let advance: f32 = self.pieces.iter().map(|it| it.advance).sum();
+        let trailing = self.pieces.iter().rev()
+            .find(|it| it.advance > 0.0)
+            .map_or(0.0, |it| it.advance - it.width);
+        advance - trailing
+    }
+
+    fn align(&self) -> TextAlign {
+        self.pieces.iter()
+            .find_map(|it| match it.kind {
+                PieceKind::Text(_, style) => Some(style.align),
+                _ => None,
+            })
+            .unwrap_or(TextAlign::Left)
+    }
+}
+
+fn draw_line(renderer: &dyn Renderer, line: &Line, baseline: Vec2) {
+    let mut x = baseline.x;
+    for piece in &line.pieces {
+        match &piece.kind {
+            PieceKind::Text(text, style) => style.draw(renderer, text, vec2(x, baseline.y)),
+            PieceKind::Icon(texture, size, color) => {
+                renderer.draw_texture(*texture, Rect::new(x, baseline.y - size.y, size.x, size.y), *color);
+            }
+            PieceKind::Break => {}
+        }
+        x += piece.advance;
+    }
+}
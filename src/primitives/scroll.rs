@@ -0,0 +1,203 @@
+use std::fmt::Debug;
+
+use macroquad::color::Color;
+use macroquad::input::MouseButton;
+use macroquad::math::Rect;
+
+use crate::core::{Ctx, Element, Phase};
+use crate::primitives::group::{calc_size_dimension, Axis, CalculatedSize, DimensionKey};
+use crate::primitives::node::Node;
+
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct ScrollState {
+    pub offset: f32,
+    drag: Option<f32>,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Scrollbar {
+    pub thickness: f32,
+    pub track: Color,
+    pub thumb: Color,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ScrollHandler {
+    pub axis: Axis,
+    pub speed: f32,
+    pub scrollbar: Option<Scrollbar>,
+}
+
+impl ScrollHandler {
+    pub fn new(axis: Axis) -> Self {
+        ScrollHandler { axis, speed: 20.0, scrollbar: None }
+    }
+
+    pub fn scrollbar(mut self, scrollbar: Scrollbar) -> Self {
+        self.scrollbar = Some(scrollbar);
+        self
+    }
+
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Metrics {
+    content: f32,
+    viewport: f32,
+    offset: f32,
+}
+
+impl Metrics {
+    fn max_offset(&self) -> f32 {
+        (self.content - self.viewport).max(0.0)
+    }
+}
+
+impl<Event: Clone> Element<Event> for ScrollHandler {
+    fn do_phase(&self, ctx: Ctx<Event>) {
+        let Some(metrics) = self.metrics(&ctx) else {
+            return;
+        };
+        match ctx.phase {
+            Phase::Layout => {}
+            Phase::Draw { renderer, .. } => {
+                if let (Some(scrollbar), Some((track, thumb))) = (self.scrollbar, self.bar_rects(&ctx, metrics)) {
+                    renderer.draw_rectangle(track, scrollbar.track);
+                    renderer.draw_rectangle(thumb, scrollbar.thumb);
+                }
+            }
+            Phase::CollectEvents { input, .. } => {
+                let previous = ctx.state::<ScrollState>().unwrap_or_default();
+                let mut state = previous;
+                let mouse = self.dimension().of(input.mouse_position);
+                let wheel = match self.dimension() {
+                    DimensionKey::Horizontal if input.mouse_wheel.x != 0.0 => input.mouse_wheel.x,
+                    _ => input.mouse_wheel.y,
+                };
+                if wheel != 0.0 && ctx.hits(input.mouse_position) {
+                    state.offset -= wheel * self.speed;
+                }
+                if !input.is_mouse_button_down(MouseButton::Left) && !input.is_mouse_button_pressed(MouseButton::Left) {
+                    state.drag = None;
+                }
+                if let Some((track, thumb)) = self.bar_rects(&ctx, metrics) {
+                    if input.is_mouse_button_pressed(MouseButton::Left) && ctx.hits(input.mouse_position) && thumb.contains(input.mouse_position) {
+                        state.drag = Some(mouse - self.dimension().of(thumb.point()));
+                    }
+                    if let Some(grab) = state.drag {
+                        let free = self.dimension().of(track.size()) - self.dimension().of(thumb.size());
+                        if free > 0.0 {
+                            state.offset = (mouse - grab - self.dimension().of(track.point())) / free * metrics.max_offset();
+                        }
+                    }
+                }
+                state.offset = state.offset.clamp(0.0, metrics.max_offset());
+                if state != previous {
+                    ctx.put_state(state);
+                }
+            }
+        }
+    }
+}
+
+impl ScrollHandler {
+    fn dimension(&self) -> DimensionKey {
+        self.axis.into()
+    }
+
+    // content extent and offset as laid out by the sibling scroll group
+    fn metrics<Event: Clone>(&self, ctx: &Ctx<Event>) -> Option<Metrics> {
+        let children = &ctx.layout?.children;
+        if children.is_empty() {
+            return None;
+        }
+        let start = children.iter().map(|it| self.dimension().of(it.rect.point())).fold(f32::INFINITY, f32::min);
+        let end = children.iter().map(|it| self.dimension().of(it.rect.point() + it.rect.size())).fold(f32::NEG_INFINITY, f32::max);
+        Some(Metrics {
+            content: end - start,
+            viewport: self.dimension().of(ctx.area.size()),
+            offset: self.dimension().of(ctx.area.point()) - start,
+        })
+    }
+
+    fn bar_rects<Event>(&self, ctx: &Ctx<Event>, metrics: Metrics) -> Option<(Rect, Rect)> {
+        let scrollbar = self.scrollbar?;
+        if metrics.content <= metrics.viewport {
+            return None;
+        }
+        let area = ctx.area;
+        let track_length = metrics.viewport;
+        let thumb_length = (track_length * metrics.viewport / metrics.content).max(scrollbar.thickness).min(track_length);
+        let thumb_start = (track_length - thumb_length) * metrics.offset / metrics.max_offset();
+        Some(match self.dimension() {
+            DimensionKey::Vertical => {
+                let x = area.x + area.w - scrollbar.thickness;
+                (
+                    Rect::new(x, area.y, scrollbar.thickness, track_length),
+                    Rect::new(x, area.y + thumb_start, scrollbar.thickness, thumb_length),
+                )
+            }
+            DimensionKey::Horizontal => {
+                let y = area.y + area.h - scrollbar.thickness;
+                (
+                    Rect::new(area.x, y, track_length, scrollbar.thickness),
+                    Rect::new(area.x + thumb_start, y, thumb_length, scrollbar.thickness),
+                )
+            }
+        })
+    }
+}
+
+fn child_sizes<Event: Clone + Debug + 'static>(
+    children: &[Node<Event>],
+    axis: DimensionKey,
+    cross_area_size: Option<f32>,
+    ctx: &Ctx<Event>,
+) -> Vec<f32> {
+    children.iter().enumerate()
+        .map(|(i, child)| calc_size_dimension(child, axis, cross_area_size, &ctx.step_down_i(i)).get_fixed_part())
+        .collect()
+}
+
+// along its axis a scroll group grows up to the natural size of its content
+pub(crate) fn measure<Event: Clone + Debug + 'static>(
+    children: &[Node<Event>],
+    axis: DimensionKey,
+    dimension: DimensionKey,
+    cross_area_size: Option<f32>,
+    ctx: &Ctx<Event>,
+) -> CalculatedSize {
+    if dimension == axis {
+        let content = child_sizes(children, axis, cross_area_size, ctx).into_iter().sum();
+        return CalculatedSize::Stretch { fixed_part: 0.0, weight: 1.0, min: None, max: Some(content) };
+    }
+    CalculatedSize::Fixed(child_sizes(children, dimension, None, ctx).into_iter().fold(0.0, f32::max))
+}
+
+pub(crate) fn layout<Event: Clone + Debug + 'static>(
+    children: &[Node<Event>],
+    axis: DimensionKey,
+    ctx: &Ctx<Event>,
+) -> Vec<Rect> {
+    let cross_extent = axis.cross().of(ctx.area.size());
+    let sizes = child_sizes(children, axis, Some(cross_extent), ctx);
+    let content: f32 = sizes.iter().sum();
+    let max_offset = (content - axis.of(ctx.area.size())).max(0.0);
+    let offset = ctx.state::<ScrollState>().map_or(0.0, |it| it.offset).clamp(0.0, max_offset);
+    let mut position = axis.of(ctx.area.point()) - offset;
+    sizes.into_iter()
+        .map(|size| {
+            let rect = match axis {
+                DimensionKey::Horizontal => Rect::new(position, ctx.area.y, size, cross_extent),
+                DimensionKey::Vertical => Rect::new(ctx.area.x, position, cross_extent, size),
+            };
+            position += size;
+            rect
+        })
+        .collect()
+}
+
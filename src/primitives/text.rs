@@ -1,12 +1,14 @@
-use macroquad::color::Color;
-use macroquad::math::{Vec2, vec2};
+use macroquad::color::{Color, WHITE};
+use macroquad::math::{Rect, Vec2, vec2};
 use macroquad::prelude::TextDimensions;
-use macroquad::text::draw_text;
-use macroquad::text::measure_text;
+use macroquad::text::Font;
 
 use crate::core::Ctx;
 use crate::core::Element;
 use crate::core::Phase;
+use crate::primitives::clip::intersect_clip;
+use crate::primitives::group::Axis;
+use crate::render::Renderer;
 
 #[derive(Debug, Clone)]
 pub struct Text {
@@ -14,44 +16,180 @@ pub struct Text {
     pub style: TextStyle,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FontMetrics {
+    pub ascent: f32,
+    pub descent: f32,
+    pub line_height: f32,
+}
+
+// glyphs reaching the font's usual ascender and descender extents
+pub(crate) const METRICS_REFERENCE: &str = "Hbdfhkl|gjpqy";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TextAlign {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TextOverflow {
+    Visible,
+    Clip,
+    Ellipsis,
+    Shrink,
+}
+
+const ELLIPSIS: &str = "…";
+
 #[derive(Debug, Clone)]
 pub struct TextStyle {
     pub font_size: f32,
     pub color: Color,
-    pub shadow: Option<Vec<(Vec2, Color)>>
+    pub shadow: Option<Vec<(Vec2, Color)>>,
+    pub font: Option<Font>,
+    pub line_spacing: f32,
+    pub align: TextAlign,
+    pub overflow: TextOverflow,
+}
+
+impl TextStyle {
+    pub fn with_font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    pub fn with_line_spacing(mut self, line_spacing: f32) -> Self {
+        self.line_spacing = line_spacing;
+        self
+    }
+
+    pub fn with_align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn with_overflow(mut self, overflow: TextOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    pub fn measure(&self, measurer: &dyn Renderer, text: &str) -> TextDimensions {
+        measurer.measure_text(text, self.font, self.font_size)
+    }
+
+    pub fn metrics(&self, measurer: &dyn Renderer) -> FontMetrics {
+        measurer.font_metrics(self.font, self.font_size)
+    }
+
+    pub fn line_height(&self, measurer: &dyn Renderer) -> f32 {
+        self.metrics(measurer).line_height * self.line_spacing
+    }
+
+    pub(crate) fn baseline_offset(&self, measurer: &dyn Renderer) -> f32 {
+        self.metrics(measurer).ascent
+    }
+
+    pub(crate) fn draw(&self, renderer: &dyn Renderer, text: &str, baseline: Vec2) {
+        let draw = |pos: Vec2, color: Color| {
+            renderer.draw_text(text, pos, self.font, self.font_size, color);
+        };
+        if let Some(shadow) = &self.shadow {
+            for (offset, color) in shadow.iter().cloned() {
+                draw(baseline + offset, color);
+            }
+        }
+        draw(baseline, self.color);
+    }
+
+    // draws one line aligned within the area, applying the overflow mode when it doesn't fit
+    pub(crate) fn draw_in(&self, measurer: &dyn Renderer, renderer: &dyn Renderer, clip: Option<Rect>, text: &str, area: Rect, baseline_y: f32) {
+        let width = self.measure(measurer, text).width;
+        let fits = width <= area.w;
+        let aligned_x = |width: f32| match self.align {
+            TextAlign::Left => area.x,
+            TextAlign::Center => area.x + (area.w - width) / 2.0,
+            TextAlign::Right => area.x + area.w - width,
+        };
+        match self.overflow {
+            TextOverflow::Clip if !fits => {
+                renderer.set_clip(Some(intersect_clip(clip, area)));
+                self.draw(renderer, text, vec2(aligned_x(width), baseline_y));
+                renderer.set_clip(clip);
+            }
+            TextOverflow::Ellipsis if !fits => {
+                let truncated = self.truncate(measurer, text, area.w);
+                let width = self.measure(measurer, &truncated).width;
+                self.draw(renderer, &truncated, vec2(aligned_x(width), baseline_y));
+            }
+            TextOverflow::Shrink if !fits && width > 0.0 => {
+                let mut style = self.clone();
+                style.font_size = (self.font_size * area.w / width).floor().max(1.0);
+                let width = style.measure(measurer, text).width;
+                style.draw(renderer, text, vec2(aligned_x(width), baseline_y));
+            }
+            _ => self.draw(renderer, text, vec2(aligned_x(width), baseline_y)),
+        }
+    }
+
+    fn truncate(&self, measurer: &dyn Renderer, text: &str, max_width: f32) -> String {
+        let mut ends: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+        while let Some(end) = ends.pop() {
+            let candidate = format!("{}{}", text[..end].trim_end(), ELLIPSIS);
+            if self.measure(measurer, &candidate).width <= max_width {
+                return candidate;
+            }
+        }
+        ELLIPSIS.to_owned()
+    }
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        TextStyle {
+            font_size: 20.0,
+            color: WHITE,
+            shadow: None,
+            font: None,
+            line_spacing: 1.0,
+            align: TextAlign::Left,
+            overflow: TextOverflow::Visible,
+        }
+    }
 }
 
 impl From<(f32, Color)> for TextStyle {
     fn from((font_size, color): (f32, Color)) -> Self {
-        TextStyle { font_size, color, shadow: None }
+        TextStyle { font_size, color, ..Default::default() }
     }
 }
 
 impl<Event> Element<Event> for Text {
     fn do_phase(&self, ctx: Ctx<Event>) {
         match ctx.phase {
-            Phase::Draw { .. } => {
-                let text = self.value.as_str();
-                let size = self.measure_self();
-                let pos = vec2(ctx.area.x, ctx.area.y + 0.725 * self.style.font_size);
-                draw_text(text, pos.x, pos.y, self.style.font_size, self.style.color);
-                let draw = |pos: Vec2, color: Color| {
-                    draw_text(text, pos.x, pos.y, self.style.font_size, color);
-                };
-                if let Some(shadow) = &self.style.shadow {
-                    for (offset, color) in shadow.iter().cloned() {
-                        draw(pos + offset, color);
-                    }
-                }
-                draw(pos, self.style.color);
+            Phase::Draw { renderer, .. } => {
+                let baseline_y = ctx.area.y + self.style.baseline_offset(ctx.renderer);
+                self.style.draw_in(ctx.renderer, renderer, ctx.clip, self.value.as_str(), ctx.area, baseline_y);
             }
-            Phase::CollectEvents { .. } => {}
+            Phase::Layout | Phase::CollectEvents { .. } => {}
         }
     }
+
+    fn baseline(&self, ctx: Ctx<Event>) -> Option<f32> {
+        Some(self.style.baseline_offset(ctx.renderer))
+    }
+
+    fn measure(&self, axis: Axis, _cross_area_size: Option<f32>, ctx: Ctx<Event>) -> Option<f32> {
+        Some(match axis {
+            Axis::Horizontal => self.measure_self(ctx.renderer).width,
+            Axis::Vertical => self.style.metrics(ctx.renderer).line_height,
+        })
+    }
 }
 
 impl Text {
-    pub fn measure_self(&self) -> TextDimensions {
-        measure_text(self.value.as_str(), None, self.style.font_size as u16, 1.0)
+    pub fn measure_self(&self, measurer: &dyn Renderer) -> TextDimensions {
+        self.style.measure(measurer, self.value.as_str())
     }
 }
@@ -1,7 +1,6 @@
 use macroquad::color::Color;
-use macroquad::math::Vec2;
-use macroquad::prelude::draw_texture_ex;
-use macroquad::texture::{DrawTextureParams, Texture2D};
+use macroquad::math::{Rect, Vec2};
+use macroquad::texture::Texture2D;
 
 use crate::core::Ctx;
 use crate::core::Element;
@@ -17,20 +16,12 @@ pub struct Texture {
 impl<Event> Element<Event> for Texture {
     fn do_phase(&self, ctx: Ctx<Event>) {
         match ctx.phase {
-            Phase::Draw { .. } => {
+            Phase::Draw { renderer, .. } => {
                 let texture = self.value;
-                let size = Vec2::new(texture.width(), texture.height());
-                let pos = Vec2::new(ctx.area.x, ctx.area.y);
-                draw_texture_ex(texture, pos.x, pos.y, self.color, DrawTextureParams {
-                    dest_size: self.dst_size.or_else(|| Some(size)),
-                    source: None,
-                    rotation: 0.0,
-                    flip_x: false,
-                    flip_y: false,
-                    pivot: None,
-                });
+                let size = self.dst_size.unwrap_or_else(|| Vec2::new(texture.width(), texture.height()));
+                renderer.draw_texture(texture, Rect::new(ctx.area.x, ctx.area.y, size.x, size.y), self.color);
             }
-            Phase::CollectEvents { .. } => {}
+            Phase::Layout | Phase::CollectEvents { .. } => {}
         }
     }
 }
@@ -0,0 +1,74 @@
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+use macroquad::math::Rect;
+
+use crate::core::{Ctx, Element};
+use crate::layout::LayoutNode;
+use crate::primitives::group::step_down_child;
+use crate::primitives::node::Node;
+
+#[derive(Clone)]
+pub struct VirtualList<Event> {
+    pub item_count: usize,
+    pub row_height: f32,
+    builder: Rc<dyn Fn(usize) -> Node<Event>>,
+}
+
+impl<Event> Debug for VirtualList<Event> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VirtualList")
+            .field("item_count", &self.item_count)
+            .field("row_height", &self.row_height)
+            .finish()
+    }
+}
+
+impl<Event> VirtualList<Event> {
+    pub fn new<F: Fn(usize) -> Node<Event> + 'static>(item_count: usize, row_height: f32, builder: F) -> Self {
+        VirtualList { item_count, row_height, builder: Rc::new(builder) }
+    }
+
+    // rows intersecting the clip, or the whole viewport when nothing clips this list
+    fn visible_rows(&self, ctx: &Ctx<Event>) -> std::ops::Range<usize> {
+        let visible = ctx.clip.unwrap_or(ctx.viewport);
+        if self.row_height <= 0.0 || ctx.area.intersect(visible).is_none() {
+            return 0..0;
+        }
+        let top = (visible.y - ctx.area.y).max(0.0);
+        let bottom = (visible.y + visible.h - ctx.area.y).max(0.0);
+        let first = ((top / self.row_height).floor() as usize).min(self.item_count);
+        let last = ((bottom / self.row_height).ceil() as usize).min(self.item_count);
+        first..last
+    }
+
+    fn row_rect(&self, ctx: &Ctx<Event>, i: usize) -> Rect {
+        Rect::new(ctx.area.x, ctx.area.y + i as f32 * self.row_height, ctx.area.w, self.row_height)
+    }
+}
+
+impl<Event: Clone + Debug + 'static> Element<Event> for VirtualList<Event> {
+    fn do_phase(&self, ctx: Ctx<Event>) {
+        let rows = self.visible_rows(&ctx);
+        let layouts = ctx.layout.map(|it| it.children.as_slice()).filter(|it| it.len() == rows.len());
+        for (k, i) in rows.enumerate() {
+            let row = (self.builder)(i).expand_padding();
+            let layout = layouts.map(|it| &it[k]);
+            let area = layout.map_or_else(|| self.row_rect(&ctx, i), |it| it.rect);
+            step_down_child(&ctx, i, &row, |ctx| row.do_phase(ctx.clone_with(|ctx| {
+                ctx.area = area;
+                ctx.layout = layout;
+            })));
+        }
+    }
+
+    fn layout(&self, ctx: Ctx<Event>) -> Vec<LayoutNode> {
+        self.visible_rows(&ctx)
+            .map(|i| {
+                let row = (self.builder)(i).expand_padding();
+                let area = self.row_rect(&ctx, i);
+                step_down_child(&ctx, i, &row, |ctx| row.layout(ctx.clone_with(|ctx| ctx.area = area)))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,107 @@
+use std::fmt::Debug;
+
+use macroquad::math::Rect;
+
+use crate::core::Ctx;
+use crate::primitives::group::{calc_size_dimension, CalculatedSize, DimensionKey};
+use crate::primitives::node::Node;
+
+#[derive(Debug, Copy, Clone)]
+struct Line {
+    start: usize,
+    end: usize,
+    cross_size: f32,
+}
+
+pub(crate) fn measure<Event: Clone + Debug + 'static>(
+    children: &[Node<Event>],
+    main: DimensionKey,
+    gap: f32,
+    dimension: DimensionKey,
+    cross_area_size: Option<f32>,
+    ctx: &Ctx<Event>,
+) -> CalculatedSize {
+    let sizes = child_sizes(children, main, ctx);
+    if dimension == main {
+        let widest = sizes.iter().flatten().map(|(main_size, _)| *main_size).fold(0.0, f32::max);
+        return CalculatedSize::stretch(widest, 1.0);
+    }
+    let lines = break_lines(&sizes, gap, cross_area_size);
+    let gaps = gap * lines.len().saturating_sub(1) as f32;
+    CalculatedSize::Fixed(lines.iter().map(|it| it.cross_size).sum::<f32>() + gaps)
+}
+
+pub(crate) fn layout<Event: Clone + Debug + 'static>(
+    children: &[Node<Event>],
+    main: DimensionKey,
+    gap: f32,
+    ctx: &Ctx<Event>,
+) -> Vec<Rect> {
+    let sizes = child_sizes(children, main, ctx);
+    let lines = break_lines(&sizes, gap, Some(main.of(ctx.area.size())));
+    let (main_start, cross_start) = match main {
+        DimensionKey::Horizontal => (ctx.area.x, ctx.area.y),
+        DimensionKey::Vertical => (ctx.area.y, ctx.area.x),
+    };
+    let mut areas = Vec::with_capacity(children.len());
+    let mut cross_offset = cross_start;
+    for line in lines {
+        let mut main_offset = main_start;
+        for size in sizes[line.start..line.end].iter().copied() {
+            let (main_size, _) = size.unwrap_or_default();
+            areas.push(match main {
+                DimensionKey::Horizontal => Rect::new(main_offset, cross_offset, main_size, line.cross_size),
+                DimensionKey::Vertical => Rect::new(cross_offset, main_offset, line.cross_size, main_size),
+            });
+            if size.is_some() {
+                main_offset += main_size + gap;
+            }
+        }
+        cross_offset += line.cross_size + gap;
+    }
+    areas
+}
+
+fn child_sizes<Event: Clone + Debug + 'static>(
+    children: &[Node<Event>],
+    main: DimensionKey,
+    ctx: &Ctx<Event>,
+) -> Vec<Option<(f32, f32)>> {
+    children.iter().enumerate()
+        .map(|(i, child)| {
+            if child.is_hidden() {
+                return None;
+            }
+            let ctx = ctx.step_down_i(i);
+            let main_size = calc_size_dimension(child, main, None, &ctx).get_fixed_part();
+            let cross_size = calc_size_dimension(child, main.cross(), Some(main_size), &ctx).get_fixed_part();
+            Some((main_size, cross_size))
+        })
+        .collect()
+}
+
+fn break_lines(sizes: &[Option<(f32, f32)>], gap: f32, available: Option<f32>) -> Vec<Line> {
+    let mut lines = vec![];
+    let mut line = Line { start: 0, end: 0, cross_size: 0.0 };
+    let mut position: Option<f32> = None;
+    for (i, size) in sizes.iter().copied().enumerate() {
+        let Some((main_size, cross_size)) = size else {
+            line.end = i + 1;
+            continue;
+        };
+        let start = position.map_or(0.0, |it| it + gap);
+        let overflows = available.is_some_and(|available| start + main_size > available);
+        if position.is_some() && overflows {
+            lines.push(line);
+            line = Line { start: i, end: i, cross_size: 0.0 };
+            position = None;
+        }
+        line.end = i + 1;
+        line.cross_size = line.cross_size.max(cross_size);
+        position = Some(position.map_or(0.0, |it| it + gap) + main_size);
+    }
+    if line.end > line.start {
+        lines.push(line);
+    }
+    lines
+}
@@ -0,0 +1,92 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+
+use macroquad::color::Color;
+use macroquad::math::{Rect, Vec2};
+use macroquad::window::get_internal_gl;
+use macroquad::shapes::{draw_rectangle, draw_rectangle_lines};
+use macroquad::text::{draw_text_ex, measure_text, Font, TextDimensions, TextParams};
+use macroquad::texture::{draw_texture_ex, DrawTextureParams, Texture2D};
+
+use crate::primitives::text::{FontMetrics, METRICS_REFERENCE};
+
+pub mod recording;
+
+pub trait Renderer: Debug {
+    fn draw_rectangle(&self, rect: Rect, color: Color);
+    fn draw_rectangle_lines(&self, rect: Rect, thickness: f32, color: Color);
+    fn draw_text(&self, text: &str, baseline: Vec2, font: Option<Font>, font_size: f32, color: Color);
+    fn draw_texture(&self, texture: Texture2D, rect: Rect, color: Color);
+    fn set_clip(&self, clip: Option<Rect>);
+    fn measure_text(&self, text: &str, font: Option<Font>, font_size: f32) -> TextDimensions;
+
+    fn font_metrics(&self, font: Option<Font>, font_size: f32) -> FontMetrics {
+        let reference = self.measure_text(METRICS_REFERENCE, font, font_size);
+        let ascent = reference.offset_y;
+        let descent = reference.height - reference.offset_y;
+        FontMetrics { ascent, descent, line_height: ascent + descent }
+    }
+}
+
+thread_local! {
+    // measuring the reference is costly, and metrics depend on font and size only
+    static METRICS_CACHE: RefCell<Vec<(Option<Font>, u16, FontMetrics)>> = const { RefCell::new(vec![]) };
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct MacroquadRenderer;
+
+impl Renderer for MacroquadRenderer {
+    fn draw_rectangle(&self, rect: Rect, color: Color) {
+        draw_rectangle(rect.x, rect.y, rect.w, rect.h, color);
+    }
+
+    fn draw_rectangle_lines(&self, rect: Rect, thickness: f32, color: Color) {
+        draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, thickness, color);
+    }
+
+    fn draw_text(&self, text: &str, baseline: Vec2, font: Option<Font>, font_size: f32, color: Color) {
+        draw_text_ex(text, baseline.x, baseline.y, TextParams {
+            font: font.unwrap_or_default(),
+            font_size: font_size as u16,
+            color,
+            ..Default::default()
+        });
+    }
+
+    fn draw_texture(&self, texture: Texture2D, rect: Rect, color: Color) {
+        draw_texture_ex(texture, rect.x, rect.y, color, DrawTextureParams {
+            dest_size: Some(rect.size()),
+            source: None,
+            rotation: 0.0,
+            flip_x: false,
+            flip_y: false,
+            pivot: None,
+        });
+    }
+
+    fn set_clip(&self, clip: Option<Rect>) {
+        let gl = unsafe { get_internal_gl() };
+        gl.quad_gl.scissor(clip.map(|rect| (rect.x as i32, rect.y as i32, rect.w as i32, rect.h as i32)));
+    }
+
+    fn measure_text(&self, text: &str, font: Option<Font>, font_size: f32) -> TextDimensions {
+        measure_text(text, font, font_size as u16, 1.0)
+    }
+
+    fn font_metrics(&self, font: Option<Font>, font_size: f32) -> FontMetrics {
+        let key = (font, font_size as u16);
+        let cached = METRICS_CACHE.with_borrow(|cache| cache.iter()
+            .find(|(font, size, _)| (*font, *size) == key)
+            .map(|(_, _, metrics)| *metrics));
+        if let Some(metrics) = cached {
+            return metrics;
+        }
+        let reference = self.measure_text(METRICS_REFERENCE, font, font_size);
+        let ascent = reference.offset_y;
+        let descent = reference.height - reference.offset_y;
+        let metrics = FontMetrics { ascent, descent, line_height: ascent + descent };
+        METRICS_CACHE.with_borrow_mut(|cache| cache.push((font, key.1, metrics)));
+        metrics
+    }
+}
@@ -0,0 +1,74 @@
+use std::cell::RefCell;
+use std::mem;
+
+use macroquad::color::Color;
+use macroquad::math::{Rect, Vec2};
+use macroquad::text::{Font, TextDimensions};
+use macroquad::texture::Texture2D;
+
+use crate::render::Renderer;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrawCommand {
+    Rectangle { rect: Rect, color: Color },
+    RectangleLines { rect: Rect, thickness: f32, color: Color },
+    Text { text: String, baseline: Vec2, font: Option<Font>, font_size: f32, color: Color },
+    Texture { texture: Texture2D, rect: Rect, color: Color },
+    Clip { rect: Option<Rect> },
+}
+
+/// Collects draw commands instead of drawing, so trees can be drawn without a window.
+/// Text is measured with fixed metrics: every char advances half the font size,
+/// and the ascent is 0.8 of it.
+#[derive(Debug, Default)]
+pub struct RecordingRenderer {
+    commands: RefCell<Vec<DrawCommand>>,
+}
+
+impl RecordingRenderer {
+    pub fn new() -> RecordingRenderer {
+        RecordingRenderer { commands: Default::default() }
+    }
+
+    pub fn commands(&self) -> Vec<DrawCommand> {
+        self.commands.borrow().clone()
+    }
+
+    pub fn take_commands(&self) -> Vec<DrawCommand> {
+        mem::take(&mut *self.commands.borrow_mut())
+    }
+
+    fn record(&self, command: DrawCommand) {
+        self.commands.borrow_mut().push(command);
+    }
+}
+
+impl Renderer for RecordingRenderer {
+    fn draw_rectangle(&self, rect: Rect, color: Color) {
+        self.record(DrawCommand::Rectangle { rect, color });
+    }
+
+    fn draw_rectangle_lines(&self, rect: Rect, thickness: f32, color: Color) {
+        self.record(DrawCommand::RectangleLines { rect, thickness, color });
+    }
+
+    fn draw_text(&self, text: &str, baseline: Vec2, font: Option<Font>, font_size: f32, color: Color) {
+        self.record(DrawCommand::Text { text: text.to_owned(), baseline, font, font_size, color });
+    }
+
+    fn draw_texture(&self, texture: Texture2D, rect: Rect, color: Color) {
+        self.record(DrawCommand::Texture { texture, rect, color });
+    }
+
+    fn set_clip(&self, clip: Option<Rect>) {
+        self.record(DrawCommand::Clip { rect: clip });
+    }
+
+    fn measure_text(&self, text: &str, _font: Option<Font>, font_size: f32) -> TextDimensions {
+        TextDimensions {
+            width: text.chars().count() as f32 * font_size * 0.5,
+            height: font_size,
+            offset_y: font_size * 0.8,
+        }
+    }
+}
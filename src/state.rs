@@ -0,0 +1,160 @@
+use std::any::TypeId;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::{iter, mem};
+
+use crate::common::any_box::AnyBox;
+use crate::core::{UiPath, UiPathSegment};
+use crate::primitives::group::Group;
+use crate::primitives::node::Node;
+
+#[derive(Debug, Clone, Default)]
+pub struct StateStore {
+    nodes: HashMap<String, HashMap<TypeId, AnyBox>>,
+    version: u64,
+}
+
+impl StateStore {
+    pub fn new() -> StateStore {
+        Default::default()
+    }
+
+    pub fn get<T: Debug + Clone + 'static>(&self, path: &str) -> Option<&T> {
+        self.nodes.get(path)?
+            .get(&TypeId::of::<T>())?
+            .cast_ref::<T>()
+    }
+
+    pub fn get_mut<T: Debug + Clone + 'static>(&mut self, path: &str) -> Option<&mut T> {
+        self.version += 1;
+        self.nodes.get_mut(path)?
+            .get_mut(&TypeId::of::<T>())?
+            .cast_mut::<T>()
+    }
+
+    pub fn put<T: Debug + Clone + 'static>(&mut self, path: &str, value: T) {
+        self.version += 1;
+        self.nodes.entry(path.to_owned())
+            .or_default()
+            .insert(TypeId::of::<T>(), AnyBox::new(value));
+    }
+
+    pub fn remove<T: Debug + Clone + 'static>(&mut self, path: &str) -> Option<T> {
+        self.version += 1;
+        self.nodes.get_mut(path)?
+            .remove(&TypeId::of::<T>())?
+            .cast::<T>()
+    }
+
+    pub fn clear_node(&mut self, path: &str) {
+        self.version += 1;
+        self.nodes.remove(path);
+    }
+
+    /// Changes on every write, so layouts that read state know when to recompute.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    // states below paths that aren't nodes of the tree, like virtual list rows,
+    // follow their closest ancestor; a `None` target drops the state
+    pub(crate) fn migrate(&mut self, moves: Vec<(String, Option<String>)>) {
+        let moves: HashMap<_, _> = moves.into_iter().collect();
+        self.nodes = mem::take(&mut self.nodes).into_iter()
+            .filter_map(|(path, state)| {
+                let (from, to) = ancestors(&path).find_map(|it| moves.get_key_value(it))?;
+                Some((format!("{}{}", to.as_ref()?, &path[from.len()..]), state))
+            })
+            .collect();
+        self.version += 1;
+    }
+}
+
+fn ancestors(path: &str) -> impl Iterator<Item=&str> {
+    iter::successors(Some(path), |it| it.rfind('/').map(|i| &it[..i]))
+}
+
+pub(crate) fn reconcile<Event: Clone + Debug + 'static>(old: &Node<Event>, new: &Node<Event>) -> Vec<(String, Option<String>)> {
+    let root = UiPath { segments: vec![UiPathSegment::Name("root")] };
+    let mut keyed = HashMap::new();
+    collect_keyed(old, &root, &mut keyed);
+    let mut moves = vec![];
+    let mut old_paths = vec![];
+    collect_paths(old, &root, &mut old_paths);
+    match_node(Some((old, root.clone())), new, root, &keyed, &mut moves);
+    let moved: HashSet<_> = moves.iter().map(|(from, _)| from.clone()).collect();
+    let removed: Vec<_> = old_paths.into_iter()
+        .filter(|it| !moved.contains(it))
+        .map(|it| (it, None))
+        .collect();
+    moves.into_iter().map(|(from, to)| (from, Some(to))).chain(removed).collect()
+}
+
+fn child_path<Event: Clone>(parent: &UiPath, index: usize, child: &Node<Event>) -> UiPath {
+    let mut path = parent.clone();
+    path.segments.push(UiPathSegment::Index(index));
+    if let Some(name) = child.get_name() {
+        path.segments.push(UiPathSegment::Name(name));
+    }
+    path
+}
+
+fn children<Event: Clone + Debug + 'static>(node: &Node<Event>) -> &[Node<Event>] {
+    node.unique::<Group<Event>>().map_or(&[], |it| it.children())
+}
+
+fn collect_keyed<'a, Event: Clone + Debug + 'static>(
+    node: &'a Node<Event>,
+    path: &UiPath,
+    keyed: &mut HashMap<String, (&'a Node<Event>, UiPath)>,
+) {
+    if let Some(key) = node.get_key() {
+        keyed.entry(key.to_owned()).or_insert_with(|| (node, path.clone()));
+    }
+    for (i, child) in children(node).iter().enumerate() {
+        collect_keyed(child, &child_path(path, i, child), keyed);
+    }
+}
+
+fn collect_paths<Event: Clone + Debug + 'static>(node: &Node<Event>, path: &UiPath, paths: &mut Vec<String>) {
+    paths.push(path.to_string());
+    for (i, child) in children(node).iter().enumerate() {
+        collect_paths(child, &child_path(path, i, child), paths);
+    }
+}
+
+fn match_node<Event: Clone + Debug + 'static>(
+    old: Option<(&Node<Event>, UiPath)>,
+    new: &Node<Event>,
+    new_path: UiPath,
+    keyed: &HashMap<String, (&Node<Event>, UiPath)>,
+    moves: &mut Vec<(String, String)>,
+) {
+    let old_children = match &old {
+        Some((old, _)) => children(old),
+        None => &[],
+    };
+    let mut used = vec![false; old_children.len()];
+    for (j, new_child) in children(new).iter().enumerate() {
+        let new_child_path = child_path(&new_path, j, new_child);
+        let old_child = match new_child.get_key() {
+            Some(key) => keyed.get(key).cloned(),
+            None => {
+                let same_slot = old_children.get(j)
+                    .filter(|it| !used[j] && it.get_key().is_none() && it.get_name() == new_child.get_name())
+                    .map(|_| j);
+                let same_name = || new_child.get_name().and_then(|name| old_children.iter().enumerate()
+                    .position(|(i, it)| !used[i] && it.get_key().is_none() && it.get_name() == Some(name)));
+                same_slot.or_else(same_name).map(|i| {
+                    used[i] = true;
+                    let (_, old_path) = old.as_ref().unwrap();
+                    (&old_children[i], child_path(old_path, i, &old_children[i]))
+                })
+            }
+        };
+        match_node(old_child, new_child, new_child_path, keyed, moves);
+    }
+    if let Some((_, old_path)) = old {
+        moves.push((old_path.to_string(), new_path.to_string()));
+    }
+}
@@ -0,0 +1,63 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use macroquad::math::Rect;
+use rust_macroquad_ui::core::{Ctx, Element};
+use rust_macroquad_ui::input::InputState;
+use rust_macroquad_ui::primitives::group::Axis;
+use rust_macroquad_ui::primitives::node::node;
+use rust_macroquad_ui::primitives::{horizontal_content, vertical_content, width};
+use rust_macroquad_ui::render::recording::RecordingRenderer;
+use rust_macroquad_ui::UILayer;
+
+#[derive(Debug, Clone)]
+struct CountedMeasure(Rc<Cell<usize>>);
+
+impl Element<()> for CountedMeasure {
+    fn measure(&self, _axis: Axis, _cross_area_size: Option<f32>, _ctx: Ctx<()>) -> Option<f32> {
+        self.0.set(self.0.get() + 1);
+        Some(10.0)
+    }
+}
+
+fn layer(measured: &Rc<Cell<usize>>) -> UILayer<()> {
+    let root = node().set(vertical_content([
+        node().set(horizontal_content([
+            node().key("measured").set(CountedMeasure(measured.clone())),
+            node().key("fixed").set(width(10.0)).set(CountedMeasure(Rc::new(Cell::new(0)))),
+        ])),
+    ]));
+    UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 100.0, 100.0))
+}
+
+#[test]
+fn measured_sizes_are_reused_across_update_draw_and_frames() {
+    let measured = Rc::new(Cell::new(0));
+    let mut layer = layer(&measured);
+    layer.layout();
+    let first = measured.get();
+    assert!(first > 0);
+    for _ in 0..3 {
+        layer.update_with(&InputState::new());
+        layer.draw_with(&RecordingRenderer::new());
+    }
+    assert_eq!(measured.get(), first);
+    // a state write lays the tree out again, but the sizes come from the cache
+    layer.states_mut().put("/root/0", 1u32);
+    layer.layout();
+    assert_eq!(measured.get(), first);
+}
+
+#[test]
+fn patching_a_node_remeasures_only_its_path() {
+    let measured = Rc::new(Cell::new(0));
+    let mut layer = layer(&measured);
+    layer.layout();
+    let first = measured.get();
+    layer.patch_key("fixed", |it| it.replace(width(20.0)));
+    assert_eq!(layer.layout().get("/root/0/1").unwrap().rect.w, 20.0);
+    assert_eq!(measured.get(), first);
+    layer.patch_key("measured", |it| it.set_hidden(false));
+    layer.layout();
+    assert!(measured.get() > first);
+}
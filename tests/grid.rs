@@ -0,0 +1,38 @@
+use macroquad::math::Rect;
+use rust_macroquad_ui::primitives::grid::Track;
+use rust_macroquad_ui::primitives::node::{node, Node};
+use rust_macroquad_ui::primitives::{grid_content, grid_span, height, width};
+use rust_macroquad_ui::UILayer;
+
+fn rects(columns: Vec<Track>, children: Vec<Node<()>>, area: Rect) -> Vec<Rect> {
+    let layer = UILayer::new(1.0, node().set(grid_content(columns, children))).with_area(area);
+    layer.layout().root.children.iter().map(|it| it.rect).collect()
+}
+
+fn cell(w: f32, h: f32) -> Node<()> {
+    node().set(width(w)).set(height(h))
+}
+
+#[test]
+fn places_cells_and_resolves_fixed_stretch_and_auto_tracks() {
+    let rects = rects(
+        vec![Track::Fixed(50.0), Track::Stretch(1.0), Track::Auto],
+        vec![cell(10.0, 15.0).set(grid_span(2, 1)), cell(40.0, 10.0), cell(10.0, 20.0), cell(1.0, 5.0)],
+        Rect::new(0.0, 0.0, 300.0, 100.0),
+    );
+    assert_eq!(rects, vec![
+        Rect::new(0.0, 0.0, 260.0, 15.0),
+        Rect::new(260.0, 0.0, 40.0, 15.0),
+        Rect::new(0.0, 15.0, 50.0, 20.0),
+        Rect::new(50.0, 15.0, 210.0, 20.0),
+    ]);
+}
+
+#[test]
+fn stretch_tracks_stay_empty_without_weight_or_space() {
+    let area = Rect::new(0.0, 0.0, 300.0, 100.0);
+    let zero_weight = rects(vec![Track::Fixed(50.0), Track::Stretch(0.0)], vec![cell(1.0, 1.0), cell(1.0, 1.0)], area);
+    assert_eq!(zero_weight[1].w, 0.0);
+    let overflowing = rects(vec![Track::Fixed(400.0), Track::Stretch(1.0)], vec![cell(1.0, 1.0), cell(1.0, 1.0)], area);
+    assert_eq!(overflowing[1].w, 0.0);
+}
@@ -0,0 +1,20 @@
+use macroquad::input::MouseButton;
+use macroquad::math::{vec2, Rect};
+use rust_macroquad_ui::input::InputState;
+use rust_macroquad_ui::primitives::mouse::on_click;
+use rust_macroquad_ui::primitives::node::node;
+use rust_macroquad_ui::primitives::{horizontal_content, width};
+use rust_macroquad_ui::UILayer;
+
+#[test]
+fn synthetic_click_reaches_the_node_under_the_mouse() {
+    let root = node().set(horizontal_content([
+        node().set(width(50.0)).set(on_click(MouseButton::Left, 1)),
+        node().set(width(50.0)).set(on_click(MouseButton::Left, 2)),
+    ]));
+    let mut layer = UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 100.0, 50.0));
+    layer.update_with(&InputState::new().mouse_at(vec2(75.0, 25.0)).press(MouseButton::Left));
+    assert_eq!(layer.get_events().copied().collect::<Vec<_>>(), vec![2]);
+    layer.update_with(&InputState::new().mouse_at(vec2(75.0, 25.0)));
+    assert_eq!(layer.get_events().count(), 0);
+}
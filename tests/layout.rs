@@ -0,0 +1,121 @@
+use std::rc::Rc;
+
+use macroquad::color::WHITE;
+use macroquad::math::Rect;
+use rust_macroquad_ui::basic_composites::label::label;
+use rust_macroquad_ui::basic_composites::node_factories::baseline_node;
+use rust_macroquad_ui::render::recording::RecordingRenderer;
+use rust_macroquad_ui::basic_composites::no_stretch::NoStretchMode::Horizontal;
+use rust_macroquad_ui::basic_composites::node_factories::no_stretch_node;
+use rust_macroquad_ui::basic_composites::stretch::stretch_horizontal;
+use rust_macroquad_ui::primitives::node::node;
+use rust_macroquad_ui::primitives::group::Align;
+use rust_macroquad_ui::primitives::{aspect_ratio, width_shrink, width_no_stretch, width_percent, height, height_percent, horizontal_content, vertical_content, width, width_max, width_min, width_stretch};
+use rust_macroquad_ui::UILayer;
+
+fn widths(layer: &UILayer<()>) -> Vec<f32> {
+    layer.layout().root.children.iter().map(|it| it.rect.w).collect()
+}
+
+#[test]
+fn stretch_children_frozen_at_bounds_leave_space_to_the_rest() {
+    let root = node().set(horizontal_content([
+        node().set(width_max(50.0)),
+        node().set(width_stretch()),
+        node().set(width_min(200.0)),
+    ]));
+    let layer = UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 300.0, 100.0));
+    assert_eq!(widths(&layer), vec![50.0, 50.0, 200.0]);
+}
+
+#[test]
+fn opposite_bound_violations_freeze_both_children() {
+    let root = node().set(horizontal_content([
+        node().set(width_max(50.0)),
+        node().set(width_min(150.0)),
+    ]));
+    let layer = UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 200.0, 100.0));
+    assert_eq!(widths(&layer), vec![50.0, 150.0]);
+}
+
+#[test]
+fn bounds_clamp_fixed_width() {
+    let root = node().set(horizontal_content([
+        node().set(width(100.0).max(50.0)),
+        node().set(width(10.0).min(30.0)),
+    ]));
+    let layer = UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 300.0, 100.0));
+    assert_eq!(widths(&layer), vec![50.0, 30.0]);
+}
+
+#[test]
+fn bounds_clamp_relative_widths_once_resolved() {
+    let root = node().set(horizontal_content([
+        node().set(width_percent(20.0).max(300.0)),
+        node().set(width_no_stretch().min(40.0)).set(horizontal_content([node().set(width(10.0))])),
+    ]));
+    let layer = UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 2000.0, 100.0));
+    assert_eq!(widths(&layer), vec![300.0, 40.0]);
+}
+
+#[test]
+fn baseline_aligned_children_without_baseline_sit_on_the_line() {
+    let root = node::<()>().set(horizontal_content([
+        node().set(width(10.0)).set(height(20.0)),
+        node().set(width(10.0)).set(height(40.0)),
+    ]).align(Align::Baseline));
+    let layer = UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 300.0, 100.0));
+    let tops: Vec<f32> = layer.layout().root.children.iter().map(|it| it.rect.y).collect();
+    assert_eq!(tops, vec![20.0, 0.0]);
+}
+
+#[test]
+fn percent_child_of_auto_sized_group_resolves_against_the_group() {
+    let root = node::<()>().set(vertical_content([
+        node().set(vertical_content([node().set(height_percent(50.0))])),
+    ]));
+    let layer = UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 100.0, 100.0));
+    let layout = layer.layout();
+    let group = &layout.root.children[0];
+    assert_eq!(group.rect.h, 100.0);
+    assert_eq!(group.children[0].rect.h, 50.0);
+}
+
+#[test]
+fn aspect_ratio_sizes_the_cross_axis_under_stretch_align() {
+    let root = node::<()>().set(horizontal_content([
+        node().set(aspect_ratio(2.0)).set(width(100.0)),
+    ]));
+    let layer = UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 300.0, 100.0));
+    let rect = layer.layout().root.children[0].rect;
+    assert_eq!((rect.w, rect.h), (100.0, 50.0));
+}
+
+#[test]
+fn shrinkable_width_keeps_its_content_size_next_to_spacers() {
+    let content = || node().set(width_shrink()).set(horizontal_content([node().set(width(70.0))]));
+    let row = |area_width: f32| {
+        let root = node().set(horizontal_content([content(), stretch_horizontal()]));
+        UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, area_width, 20.0))
+    };
+    assert_eq!(widths(&row(120.0)), vec![70.0, 50.0]);
+    assert_eq!(widths(&row(60.0)), vec![60.0, 0.0]);
+    let panel = node().set(horizontal_content([no_stretch_node(Horizontal, content()), stretch_horizontal()]));
+    let layer = UILayer::new(1.0, panel).with_area(Rect::new(0.0, 0.0, 120.0, 20.0));
+    assert_eq!(widths(&layer), vec![70.0, 50.0]);
+}
+
+#[test]
+fn baseline_row_measures_room_for_descent_below_the_line() {
+    let root = node::<()>().set(vertical_content([
+        baseline_node([node().set(width(32.0)).set(height(32.0)), label("text", (20.0, WHITE))]),
+    ]));
+    let layer = UILayer::new(1.0, root)
+        .with_area(Rect::new(0.0, 0.0, 200.0, 100.0))
+        .with_renderer(Rc::new(RecordingRenderer::new()));
+    let layout = layer.layout();
+    let row = &layout.root.children[0];
+    assert_eq!(row.rect.h, 36.0);
+    assert_eq!(row.children[1].rect.y, 16.0);
+    assert!(row.children.iter().all(|it| it.rect.bottom() <= row.rect.bottom()));
+}
@@ -0,0 +1,39 @@
+use std::rc::Rc;
+
+use macroquad::color::{BLUE, RED, WHITE};
+use macroquad::math::{vec2, Rect};
+use rust_macroquad_ui::basic_composites::label::label;
+use rust_macroquad_ui::primitives::border::border;
+use rust_macroquad_ui::primitives::node::node;
+use rust_macroquad_ui::primitives::{color_fill, horizontal_content, width};
+use rust_macroquad_ui::render::recording::{DrawCommand, RecordingRenderer};
+use rust_macroquad_ui::UILayer;
+
+#[test]
+fn draws_tree_without_text_headless() {
+    let root = node::<()>().set(horizontal_content([
+        node().set(width(40.0)).set(color_fill(RED)),
+        node().set(width(60.0)).set(border(2.0, BLUE)),
+    ]));
+    let layer = UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 100.0, 50.0));
+    let renderer = RecordingRenderer::new();
+    layer.draw_with(&renderer);
+    assert_eq!(renderer.commands(), vec![
+        DrawCommand::Rectangle { rect: Rect::new(0.0, 0.0, 40.0, 50.0), color: RED },
+        DrawCommand::RectangleLines { rect: Rect::new(40.0, 0.0, 60.0, 50.0), thickness: 2.0, color: BLUE },
+    ]);
+}
+
+#[test]
+fn records_label_text_with_deterministic_metrics() {
+    let root = node::<()>().set(horizontal_content([label("Hello", (20.0, WHITE))]));
+    let renderer = Rc::new(RecordingRenderer::new());
+    let layer = UILayer::new(1.0, root)
+        .with_area(Rect::new(0.0, 0.0, 100.0, 50.0))
+        .with_renderer(renderer.clone());
+    layer.draw();
+    assert_eq!(layer.layout().root.children[0].rect, Rect::new(0.0, 0.0, 50.0, 50.0));
+    assert_eq!(renderer.commands(), vec![
+        DrawCommand::Text { text: "Hello".to_owned(), baseline: vec2(0.0, 16.0), font: None, font_size: 20.0, color: WHITE },
+    ]);
+}
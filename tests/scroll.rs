@@ -0,0 +1,77 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use macroquad::input::MouseButton;
+use macroquad::math::{vec2, Rect};
+use macroquad::color::{DARKGRAY, GRAY};
+use rust_macroquad_ui::basic_composites::scroll_view::{scroll_view, scroll_view_with_scrollbar};
+use rust_macroquad_ui::basic_composites::virtual_list::virtual_list;
+use rust_macroquad_ui::input::InputState;
+use rust_macroquad_ui::primitives::group::Axis;
+use rust_macroquad_ui::primitives::mouse::on_click;
+use rust_macroquad_ui::primitives::node::{node, Node};
+use rust_macroquad_ui::primitives::scroll::Scrollbar;
+use rust_macroquad_ui::primitives::{height, vertical_content};
+use rust_macroquad_ui::UILayer;
+
+fn items() -> Node<usize> {
+    node().set(vertical_content((0..10)
+        .map(|i| node().set(height(30.0)).set(on_click(MouseButton::Left, i)))
+        .collect::<Vec<_>>()))
+}
+
+fn first_item_top(layer: &UILayer<usize>) -> f32 {
+    layer.layout().get("/root/0/scroll_view/0/0").unwrap().rect.y
+}
+
+#[test]
+fn wheel_moves_content_and_clamps_at_max_offset() {
+    let root = node().set(vertical_content([scroll_view(Axis::Vertical, items())]));
+    let mut layer = UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 100.0, 100.0));
+    layer.update_with(&InputState::new().mouse_at(vec2(50.0, 50.0)).wheel(vec2(0.0, -1.0)));
+    assert_eq!(first_item_top(&layer), -20.0);
+    layer.update_with(&InputState::new().mouse_at(vec2(50.0, 50.0)).wheel(vec2(0.0, -100.0)));
+    assert_eq!(first_item_top(&layer), -200.0);
+    layer.update_with(&InputState::new().mouse_at(vec2(50.0, 50.0)).wheel(vec2(0.0, 100.0)));
+    assert_eq!(first_item_top(&layer), 0.0);
+}
+
+#[test]
+fn clicks_outside_the_clip_rect_miss_scrolled_content() {
+    let root = node().set(vertical_content([scroll_view(Axis::Vertical, items()).set(height(50.0)), node().set(height(50.0))]));
+    let mut layer = UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 100.0, 100.0));
+    layer.update_with(&InputState::new().mouse_at(vec2(50.0, 40.0)).press(MouseButton::Left));
+    assert_eq!(layer.get_events().copied().collect::<Vec<_>>(), vec![1]);
+    layer.update_with(&InputState::new().mouse_at(vec2(50.0, 55.0)).press(MouseButton::Left));
+    assert_eq!(layer.get_events().count(), 0);
+}
+
+#[test]
+fn dragging_the_thumb_scrolls_proportionally() {
+    let scrollbar = Scrollbar { thickness: 10.0, track: GRAY, thumb: DARKGRAY };
+    let root = node().set(vertical_content([scroll_view_with_scrollbar(Axis::Vertical, scrollbar, items())]));
+    let mut layer = UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 100.0, 100.0));
+    layer.update_with(&InputState::new().mouse_at(vec2(95.0, 10.0)).press(MouseButton::Left));
+    assert_eq!(first_item_top(&layer), 0.0);
+    // the thumb is a third of the track, so half of its free travel scrolls half of the content overflow
+    let half_travel = (100.0 - 100.0 / 3.0) / 2.0;
+    layer.update_with(&InputState::new().mouse_at(vec2(95.0, 10.0 + half_travel)).hold(MouseButton::Left));
+    assert!((first_item_top(&layer) + 100.0).abs() < 0.01);
+}
+
+#[test]
+fn virtual_list_builds_only_visible_rows() {
+    let built = Rc::new(Cell::new(0));
+    let counter = built.clone();
+    let list = virtual_list(1000, 20.0, move |i| {
+        counter.set(counter.get() + 1);
+        node().set(height(20.0)).set(on_click(MouseButton::Left, i))
+    });
+    let root = node().set(vertical_content([scroll_view(Axis::Vertical, list)]));
+    let layer = UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 100.0, 100.0));
+    let layout = layer.layout();
+    let rows = &layout.get("/root/0/scroll_view/0/virtual_list").unwrap().children;
+    assert_eq!(rows.len(), 5);
+    assert_eq!(rows[4].rect, Rect::new(0.0, 80.0, 100.0, 20.0));
+    assert_eq!(built.get(), 5);
+}
@@ -0,0 +1,60 @@
+use rust_macroquad_ui::basic_composites::virtual_list::virtual_list;
+use rust_macroquad_ui::primitives::node::{node, Node};
+use rust_macroquad_ui::primitives::vertical_content;
+use rust_macroquad_ui::UILayer;
+
+const ROW_STATE: &str = "/root/0/virtual_list/0/scroll_view";
+
+fn tree() -> Node<()> {
+    node().set(vertical_content([virtual_list(10, 20.0, |_| node())]))
+}
+
+#[test]
+fn state_below_virtual_rows_survives_identical_root() {
+    let mut layer = UILayer::new(1.0, tree());
+    layer.states_mut().put(ROW_STATE, 7u32);
+    layer.set_root(tree());
+    assert_eq!(layer.states().get::<u32>(ROW_STATE), Some(&7));
+}
+
+#[test]
+fn state_below_removed_nodes_is_dropped() {
+    let mut layer = UILayer::new(1.0, tree());
+    layer.states_mut().put(ROW_STATE, 7u32);
+    layer.set_root(node().set(vertical_content([node()])));
+    assert_eq!(layer.states().get::<u32>(ROW_STATE), None);
+}
+
+#[test]
+fn keyed_children_keep_state_when_swapped() {
+    let keyed = |keys: [&str; 2]| node::<()>().set(vertical_content(keys.map(|key| node().key(key))));
+    let mut layer = UILayer::new(1.0, keyed(["a", "b"]));
+    layer.states_mut().put("/root/0", "a");
+    layer.states_mut().put("/root/1", "b");
+    layer.set_root(keyed(["b", "a"]));
+    assert_eq!(layer.states().get::<&str>("/root/0"), Some(&"b"));
+    assert_eq!(layer.states().get::<&str>("/root/1"), Some(&"a"));
+}
+
+#[test]
+fn renamed_unkeyed_child_loses_state() {
+    let named = |name| node::<()>().set(vertical_content([node().name(name)]));
+    let mut layer = UILayer::new(1.0, named("first"));
+    layer.states_mut().put("/root/0/first", 1u32);
+    layer.set_root(named("second"));
+    assert_eq!(layer.states().get::<u32>("/root/0/first"), None);
+    assert_eq!(layer.states().get::<u32>("/root/0/second"), None);
+}
+
+#[test]
+fn keyed_node_keeps_state_when_moved_to_another_parent() {
+    let tree = |first: Vec<Node<()>>, second: Vec<Node<()>>| node().set(vertical_content([
+        node().set(vertical_content(first)),
+        node().set(vertical_content(second)),
+    ]));
+    let mut layer = UILayer::new(1.0, tree(vec![node().key("moving")], vec![]));
+    layer.states_mut().put("/root/0/0", 1u32);
+    layer.set_root(tree(vec![], vec![node(), node().key("moving")]));
+    assert_eq!(layer.states().get::<u32>("/root/1/1"), Some(&1));
+    assert_eq!(layer.states().get::<u32>("/root/0/0"), None);
+}
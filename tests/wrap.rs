@@ -0,0 +1,24 @@
+use macroquad::math::Rect;
+use rust_macroquad_ui::primitives::node::node;
+use rust_macroquad_ui::primitives::{height, horizontal_wrap_content, vertical_content, width};
+use rust_macroquad_ui::UILayer;
+
+#[test]
+fn vertical_parent_gives_wrap_group_its_wrapped_height() {
+    let item = || node().set(width(40.0)).set(height(10.0));
+    let root = node::<()>().set(vertical_content([
+        node().set(horizontal_wrap_content([item(), item(), item()]).gap(2.0)),
+        node().set(height(5.0)),
+    ]));
+    let layer = UILayer::new(1.0, root).with_area(Rect::new(0.0, 0.0, 100.0, 200.0));
+    let layout = layer.layout();
+    let wrap = &layout.root.children[0];
+    assert_eq!(wrap.rect.h, 22.0);
+    assert_eq!(layout.root.children[1].rect.y, 22.0);
+    let items: Vec<_> = wrap.children.iter().map(|it| it.rect).collect();
+    assert_eq!(items, vec![
+        Rect::new(0.0, 0.0, 40.0, 10.0),
+        Rect::new(42.0, 0.0, 40.0, 10.0),
+        Rect::new(0.0, 12.0, 40.0, 10.0),
+    ]);
+}